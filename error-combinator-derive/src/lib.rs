@@ -0,0 +1,310 @@
+//! Derive macro for [`error-combinator`](https://crates.io/crates/error-combinator).
+//!
+//! Use it through the `derive` feature of `error-combinator`:
+//! `error_combinator::validate::Validate`.
+
+use std::collections::HashMap;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input,
+    spanned::Spanned,
+    Data,
+    DeriveInput,
+    Fields,
+    GenericParam,
+    Ident,
+    Path,
+    Type,
+};
+
+/// Derives `error_combinator::validate::Validate` for a struct with named fields.
+/// The struct may have lifetime parameters, but no type or const parameters.
+///
+/// ```
+/// # use error_combinator::validate::Validate;
+/// # #[derive(Debug, PartialEq)]
+/// # enum ValidateErr { Name, Age }
+/// # fn check_name(name: &String) -> Result<(), ValidateErr> {
+/// #     if name.is_empty() { Err(ValidateErr::Name) } else { Ok(()) }
+/// # }
+/// # fn check_age(age: &u32) -> Result<(), ValidateErr> {
+/// #     if *age < 150 { Ok(()) } else { Err(ValidateErr::Age) }
+/// # }
+/// #[derive(Validate)]
+/// #[validate(error = ValidateErr)]
+/// struct User {
+///     #[check(check_name)]
+///     name: String,
+///     #[check(check_age)]
+///     age: u32,
+/// }
+///
+/// let user = User { name: String::new(), age: 200 };
+/// assert_eq!(user.validate().err(), Some(vec![ValidateErr::Name, ValidateErr::Age]));
+/// ```
+///
+/// Each `#[check(fn_name)]` function must have the signature
+/// `fn(&FieldType) -> Result<(), ValidateErr>`.
+/// For `User` the following items are generated:
+///
+/// - `UserState<Name, Age>`: the typestate struct, one parameter per checked field
+/// - `UserNamePre`/`UserNamePost`, `UserAgePre`/`UserAgePost`: the state
+///   before and after each field check
+/// - `impl Validate for User` with `Error = Vec<ValidateErr>`
+///
+/// Two checked fields whose names only differ by underscores, such as `foo`
+/// and `foo_`, would share a state parameter and are rejected:
+///
+/// ```compile_fail
+/// # use error_combinator::validate::Validate;
+/// # fn check(_: &u32) -> Result<(), ()> { Ok(()) }
+/// #[derive(Validate)]
+/// #[validate(error = ())]
+/// struct Pair {
+///     #[check(check)]
+///     foo: u32,
+///     #[check(check)]
+///     foo_: u32,
+/// }
+/// ```
+#[proc_macro_derive(Validate, attributes(validate, check))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+struct CheckedField {
+    ident: Ident,
+    check: Path,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let vis = &input.vis;
+
+    if let Some(param) = input
+        .generics
+        .params
+        .iter()
+        .find(|param| !matches!(param, GenericParam::Lifetime(_)))
+    {
+        return Err(syn::Error::new(
+            param.span(),
+            "`Validate` cannot be derived for structs with type or const parameters, \
+             only lifetime parameters are supported",
+        ));
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "`Validate` can only be derived for structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                name.span(),
+                "`Validate` can only be derived for structs",
+            ));
+        }
+    };
+
+    let error = error_type(&input)?;
+
+    let mut checked = Vec::new();
+    for field in fields {
+        let mut check = None;
+        for attr in &field.attrs {
+            if !attr.path().is_ident("check") {
+                continue;
+            }
+            if check.is_some() {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "only one `#[check(...)]` attribute is allowed per field",
+                ));
+            }
+            check = Some(attr.parse_args::<Path>()?);
+        }
+        if let Some(check) = check {
+            checked.push(CheckedField {
+                ident: field.ident.clone().unwrap(),
+                check,
+            });
+        }
+    }
+
+    // each field becomes a type parameter named after it, so `foo` and
+    // `foo_` would clash
+    let mut camels: HashMap<String, &Ident> = HashMap::new();
+    for f in &checked {
+        if let Some(other) = camels.insert(camel_case(&f.ident.to_string()), &f.ident) {
+            return Err(syn::Error::new(
+                f.ident.span(),
+                format!(
+                    "fields `{}` and `{}` both map to the state parameter `{}`, rename one of them",
+                    other,
+                    f.ident,
+                    camel_case(&f.ident.to_string()),
+                ),
+            ));
+        }
+    }
+
+    if checked.is_empty() {
+        return Err(syn::Error::new(
+            name.span(),
+            "`Validate` requires at least one field with `#[check(fn_name)]`",
+        ));
+    }
+
+    let krate = quote!(::error_combinator);
+    let checked_ty = quote!(#krate::state::Checked);
+    let unchecked_ty = quote!(#krate::state::Unchecked);
+
+    let state_name = format_ident!("{}State", name);
+    let params: Vec<Ident> = checked
+        .iter()
+        .map(|f| Ident::new(&camel_case(&f.ident.to_string()), Span::call_site()))
+        .collect();
+    let markers: Vec<Ident> = checked
+        .iter()
+        .map(|f| format_ident!("_{}", f.ident))
+        .collect();
+
+    // state type where the first `n` checks have been performed
+    let state_after = |n: usize| {
+        let args = (0..checked.len()).map(|i| {
+            if i < n {
+                checked_ty.clone()
+            } else {
+                unchecked_ty.clone()
+            }
+        });
+        quote!(#state_name<#(#args),*>)
+    };
+
+    let mut aliases = Vec::new();
+    let mut steps = Vec::new();
+    let single = checked.len() == 1;
+    for (i, f) in checked.iter().enumerate() {
+        let camel = camel_case(&f.ident.to_string());
+        let pre_alias = format_ident!("{}{}Pre", name, camel);
+        let post_alias = format_ident!("{}{}Post", name, camel);
+        let pre = state_after(i);
+        let post = state_after(i + 1);
+        let field_name = f.ident.to_string();
+        let pre_doc = format!("State before `{}::{}` is checked", name, field_name);
+        let post_doc = format!("State after `{}::{}` is checked", name, field_name);
+        aliases.push(quote! {
+            #[doc = #pre_doc]
+            #vis type #pre_alias = #pre;
+            #[doc = #post_doc]
+            #vis type #post_alias = #post;
+        });
+
+        let ident = &f.ident;
+        let check = &f.check;
+        let body = if single {
+            quote!(#check(&v.#ident).map_err(|e| ::std::vec![e]))
+        } else {
+            quote!(#check(&v.#ident))
+        };
+        steps.push(quote! {
+            #krate::check::check_ref::<Self, #pre_alias, #post_alias, _, _>(
+                |v: &Self| #body
+            )
+        });
+    }
+
+    let combine = quote!(#krate::cmberr::VecCombine<#error>);
+    let chain = |method: Ident| {
+        steps[1..].iter().fold(steps[0].clone(), |acc, step| {
+            quote!(#krate::check::Check::#method::<_, #combine>(#acc, #step))
+        })
+    };
+    let check_first = chain(format_ident!("and"));
+    let check_all = chain(format_ident!("or"));
+
+    let unchecked_state = state_after(0);
+    let checked_state = state_after(checked.len());
+    let state_doc = format!("Typestate of [`{}`], one parameter per checked field", name);
+
+    Ok(quote! {
+        #[doc = #state_doc]
+        #vis struct #state_name<#(#params),*> {
+            #(#markers: ::std::marker::PhantomData<#params>),*
+        }
+
+        #(#aliases)*
+
+        impl #impl_generics #krate::validate::Validate for #name #ty_generics #where_clause {
+            type Unchecked = #unchecked_state;
+            type Checked = #checked_state;
+            type Error = ::std::vec::Vec<#error>;
+
+            fn check_first<'__check>()
+                -> impl #krate::check::Validator<&'__check Self, Self::Unchecked, PostState = Self::Checked, Error = Self::Error>
+                where Self: '__check
+            {
+                #check_first
+            }
+
+            fn check_all<'__check>()
+                -> impl #krate::check::Validator<&'__check Self, Self::Unchecked, PostState = Self::Checked, Error = Self::Error>
+                where Self: '__check
+            {
+                #check_all
+            }
+        }
+    })
+}
+
+/// Reads the error type from `#[validate(error = ErrorType)]`
+fn error_type(input: &DeriveInput) -> syn::Result<Type> {
+    let mut error = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("error") {
+                error = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `validate` attribute, expected `error = Type`"))
+            }
+        })?;
+    }
+    error.ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "`Validate` requires `#[validate(error = ErrorType)]`",
+        )
+    })
+}
+
+fn camel_case(s: &str) -> String {
+    s.trim_start_matches("r#")
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests_derive {
+    use error_combinator::{
+        check::{
+            Check, CheckState, check_ref
+        },
+        cmberr::VecCombine,
+        validate::Validate,
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        Name,
+        Age,
+    }
+
+    fn check_name(data: &str) -> Result<(), ValidateErr> {
+        if data.is_empty() {
+            Err(ValidateErr::Name)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_age(data: &u32) -> Result<(), ValidateErr> {
+        if *data < 150 {
+            Ok(())
+        } else {
+            Err(ValidateErr::Age)
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(error = ValidateErr)]
+    struct User {
+        #[check(check_name)]
+        name: String,
+        #[check(check_age)]
+        age: u32,
+        #[allow(dead_code)]
+        note: String,
+    }
+
+    #[derive(Validate)]
+    #[validate(error = ValidateErr)]
+    struct Pet {
+        #[check(check_name)]
+        name: String,
+    }
+
+    #[derive(Validate)]
+    #[validate(error = ValidateErr)]
+    struct Form<'a> {
+        #[check(check_name)]
+        name: &'a str,
+    }
+
+    /// a check that may only run after every `User` field has been checked,
+    /// see `derive_works01`
+    fn check_not_root(user: &User) -> Result<(), ValidateErr> {
+        if user.name == "root" {
+            Err(ValidateErr::Name)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn derive_works00() {
+        let check_list = [
+            (("tom", 20),  None),
+            (("", 20),     Some(vec![ValidateErr::Name])),
+            (("tom", 200), Some(vec![ValidateErr::Age])),
+            (("", 200),    Some(vec![ValidateErr::Name, ValidateErr::Age])),
+        ];
+
+        for ((name, age), ans) in check_list {
+            let user = User { name: name.to_string(), age, note: String::new() };
+            assert_eq!(user.validate().err(), ans);
+
+            let first = User::check_first()
+                .check(CheckState::new(&user))
+                .to_result()
+                .err();
+            assert_eq!(first, ans.map(|mut v| { v.truncate(1); v }));
+        }
+
+        let pet = Pet { name: String::new() };
+        assert_eq!(pet.validate().err(), Some(vec![ValidateErr::Name]));
+    }
+
+    /// generated transition types can be used by hand-written checks
+    #[test]
+    fn derive_works01() {
+        let user = User { name: "root".to_string(), age: 20, note: String::new() };
+        let checker = User::check_first()
+            .and::<_, VecCombine<ValidateErr>>(
+                check_ref::<User, UserAgePost, UserAgePost, _, _>(check_not_root)
+            );
+        let r = checker.check(CheckState::new(&user)).to_result();
+        assert_eq!(r.err(), Some(vec![ValidateErr::Name]));
+    }
+
+    /// structs may borrow their fields
+    #[test]
+    fn derive_works02() {
+        let name = String::from("tom");
+        let form = Form { name: &name };
+        assert!(form.validate().is_ok());
+        assert_eq!(Form { name: "" }.validate().err(), Some(vec![ValidateErr::Name]));
+    }
+}
@@ -0,0 +1,65 @@
+//!
+//! ```sh
+//! cargo run --example derive00 --features derive
+//! ```
+//!
+
+use error_combinator::validate::Validate;
+
+#[derive(Debug)]
+enum ValidateErr {
+    NameEmpty,
+    NameTooLong,
+    AgeOutOfRange,
+}
+
+fn check_name(data: &str) -> Result<(), ValidateErr> {
+    if data.is_empty() {
+        Err(ValidateErr::NameEmpty)
+    } else if 16 < data.len() {
+        Err(ValidateErr::NameTooLong)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_age(data: &u32) -> Result<(), ValidateErr> {
+    if *data < 150 {
+        Ok(())
+    } else {
+        Err(ValidateErr::AgeOutOfRange)
+    }
+}
+
+/// `#[derive(Validate)]` generates `UserState<Name, Age>`,
+/// the per-field transition types and the `Validate` impl.
+#[derive(Validate)]
+#[validate(error = ValidateErr)]
+struct User {
+    #[check(check_name)]
+    name: String,
+    #[check(check_age)]
+    age: u32,
+}
+
+fn main() {
+    let check_list = [
+        User { name: "tom".to_string(), age: 20 },
+        User { name: "".to_string(), age: 20 },
+        User { name: "a very very long name".to_string(), age: 200 },
+    ];
+
+    for user in check_list {
+        println!("test case: \"{}\", {}", user.name, user.age);
+        match user.validate() {
+            Ok(_v) => {
+                println!("Passed!");
+            }
+            Err(err) => {
+                println!("Failed because");
+                println!("{:?}", err)
+            }
+        }
+        println!("---")
+    }
+}
@@ -2,7 +2,8 @@ use error_combinator::{
     check::{
         Check,        // trait
         CheckOutcome, // struct
-        CheckState    // struct
+        CheckState,   // struct
+        check_ref     // fn
     },
 };
 
@@ -25,16 +26,14 @@ fn check_starts_with_hello(
 -> 
 CheckOutcome<&str, ErrState<Checked>, ValidateErr>
 {
-    if data.value.starts_with("hello") {
-        CheckOutcome::Passed(
-            CheckState::new(data.value)
-        )
-    } else {
-        CheckOutcome::Failed{
-            state: CheckState::new(data.value),
-            err: ValidateErr::CheckStartsWithHelloErr
+    check_ref(|value: &str| {
+        if value.starts_with("hello") {
+            Ok(())
+        } else {
+            Err(ValidateErr::CheckStartsWithHelloErr)
         }
-    }
+    })
+    .check(data)
 }
 
 fn main() {
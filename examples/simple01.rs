@@ -19,6 +19,7 @@ enum ValidateErr {
     CheckLength10,
 }
 
+#[allow(clippy::ptr_arg)]
 fn check_length_10(data: &Vec<i32>) -> Result<(), ValidateErr> {
     if data.len() == 10 {
         Ok(())
@@ -27,6 +28,7 @@ fn check_length_10(data: &Vec<i32>) -> Result<(), ValidateErr> {
     }
 }
 
+#[allow(clippy::ptr_arg)]
 fn check_start_with_0(data: &Vec<i32>) -> Result<(), ValidateErr> {
     if data[0] == 0 {
         Ok(())
@@ -50,7 +52,7 @@ fn main() {
     );
     match r {
         CheckOutcome::Passed(v) => {
-            println!("test case: {:?}", v.value);
+            println!("test case: {:?}", *v);
             println!("Passed!");
         }
         CheckOutcome::Failed{state, err} => {
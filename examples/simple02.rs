@@ -2,7 +2,8 @@ use error_combinator::{
     check::{
         Check,        // trait
         CheckOutcome, // struct
-        CheckState    // struct
+        CheckState,   // struct
+        check_noref   // fn
     },
 };
 
@@ -25,16 +26,14 @@ fn check_length_10(
 -> 
 CheckOutcome<Vec<usize>, ErrState<Checked>, ValidateErr>
 {
-    if data.value.len() == 10 {
-        CheckOutcome::Passed(
-            CheckState::new(data.value)
-        )
-    } else {
-        CheckOutcome::Failed{
-            state: CheckState::new(data.value),
-            err: ValidateErr::CheckStartsWithHelloErr
+    check_noref(|value: &Vec<usize>| {
+        if value.len() == 10 {
+            Ok(())
+        } else {
+            Err(ValidateErr::CheckStartsWithHelloErr)
         }
-    }
+    })
+    .check(data)
 }
 
 fn main() {
@@ -47,7 +46,7 @@ fn main() {
     );
     match r {
         CheckOutcome::Passed(v) => {
-            println!("test case: {:?}", v.value);
+            println!("test case: {:?}", *v);
             println!("Passed!");
         }
         CheckOutcome::Failed{state, err} => {
@@ -6,7 +6,7 @@
 
 use error_combinator::{
     check::{
-        Check, CheckOutcome, CheckState
+        Check, CheckOutcome, CheckState, check_ref
     },
     cmberr::{
         VecCombine
@@ -23,6 +23,7 @@ struct ErrState<CheckStartsWithHello, CheckMin3, CheckEndsWithWorld, CheckInclud
     _check_includes_abc: PhantomData<CheckIncludesAbc>
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
 enum ValidateErr {
     CheckStartsWithHelloErr,
@@ -36,16 +37,14 @@ fn check_starts_with_hello(
 -> 
 CheckOutcome<&str, ErrState<Checked, Unchecked, Unchecked, Unchecked>, ValidateErr>
 {
-    if data.value.starts_with("hello") {
-        CheckOutcome::Passed(
-            CheckState::new(data.value)
-        )
-    } else {
-        CheckOutcome::Failed{
-            state: CheckState::new(data.value),
-            err: ValidateErr::CheckStartsWithHelloErr
+    check_ref(|value: &str| {
+        if value.starts_with("hello") {
+            Ok(())
+        } else {
+            Err(ValidateErr::CheckStartsWithHelloErr)
         }
-    }
+    })
+    .check(data)
 }
 
 fn check_min6(
@@ -53,17 +52,14 @@ fn check_min6(
 -> 
 CheckOutcome<&str, ErrState<Checked, Checked, Unchecked, Unchecked>, ValidateErr>
 {
-    if 6 < data.value.len() {
-        CheckOutcome::Passed(
-            CheckState::new(data.value)
-        )
-    }
-    else {
-        CheckOutcome::Failed{
-            state: CheckState::new(data.value),
-            err: ValidateErr::CheckMin6Err
+    check_ref(|value: &str| {
+        if 6 < value.len() {
+            Ok(())
+        } else {
+            Err(ValidateErr::CheckMin6Err)
         }
-    }
+    })
+    .check(data)
 }
 
 fn check_ends_with_world(
@@ -71,16 +67,14 @@ fn check_ends_with_world(
 -> 
 CheckOutcome<&str, ErrState<Checked, Checked, Checked, Unchecked>, ValidateErr>
 {
-    if data.value.ends_with("world") {
-        CheckOutcome::Passed(
-            CheckState::new(data.value),
-        )
-    } else {
-        CheckOutcome::Failed{
-            state: CheckState::new(data.value),
-            err: ValidateErr::CheckEndsWithWorldErr
+    check_ref(|value: &str| {
+        if value.ends_with("world") {
+            Ok(())
+        } else {
+            Err(ValidateErr::CheckEndsWithWorldErr)
         }
-    }
+    })
+    .check(data)
 }
 
 fn check_includes_abc(
@@ -88,16 +82,14 @@ fn check_includes_abc(
 -> 
 CheckOutcome<&str, ErrState<Checked, Checked, Checked, Checked>, ValidateErr>
 {
-    if let Some(_) = data.value.find("abc") {
-        CheckOutcome::Passed(
-            CheckState::new(data.value)
-        )
-    } else {
-        CheckOutcome::Failed{
-            state: CheckState::new(data.value),
-            err: ValidateErr::CheckIncludesAbcErr
+    check_ref(|value: &str| {
+        if value.contains("abc") {
+            Ok(())
+        } else {
+            Err(ValidateErr::CheckIncludesAbcErr)
         }
-    }
+    })
+    .check(data)
 }
 
 fn main() {
@@ -6,7 +6,7 @@
 
 use error_combinator::{
     check::{
-        Check, CheckOutcome, CheckState, check_ref
+        Check, CheckState, check_ref
     },
     cmberr::VecCombine
 };
@@ -21,6 +21,7 @@ struct ErrState<CheckStartsWithHello, CheckMin3, CheckEndsWithWorld, CheckInclud
     _check_includes_abc: PhantomData<CheckIncludesAbc>
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
 enum ValidateErr {
     CheckStartsWithHelloErr,
@@ -75,7 +76,7 @@ fn check_includes_abc(
 -> 
 Result<(), ValidateErr>
 {
-    if let Some(_) = data.find("abc") {
+    if data.contains("abc") {
         Ok(())
     } else {
         Err(ValidateErr::CheckIncludesAbcErr)
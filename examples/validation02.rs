@@ -23,6 +23,7 @@ struct ErrState<CheckStartsWithHello, CheckMin3, CheckEndsWithWorld, CheckInclud
     _check_includes_abc: PhantomData<CheckIncludesAbc>
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug)]
 enum ValidateErr {
     CheckStartsWithHelloErr,
@@ -77,7 +78,7 @@ fn check_includes_abc(
 -> 
 Result<(), ValidateErr>
 {
-    if let Some(_) = data.find("abc") {
+    if data.contains("abc") {
         Ok(())
     } else {
         Err(ValidateErr::CheckIncludesAbcErr)
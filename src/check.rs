@@ -1,9 +1,25 @@
-use std::marker::PhantomData;
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::Deref,
+    panic,
+    thread
+};
 
 use crate::cmberr::{
     CombineErrorBuilder,
     CombineError
 };
+use crate::state::Unverified;
+
+pub mod async_check;
+pub mod cond;
+pub mod dynamic;
+pub mod each;
+pub mod field;
+pub mod option;
+pub mod parse;
+pub mod rules;
 
 pub trait Check<T, PreState> 
     where Self:Sized
@@ -22,6 +38,15 @@ pub trait Check<T, PreState>
         And { a: self, b, _combine:PhantomData }
     }
 
+    /// Runs `b` after `self` and accumulates the errors of both.
+    ///
+    /// `b` also runs when `self` failed, so that its error is reported as
+    /// well. It then receives the value at the post-state of `self` although
+    /// `self` did not pass: a deliberate exception to the typestate, which
+    /// lets one pass report every failed check. The outcome is failed either
+    /// way, so the value never leaves in a passed state. A check that relies
+    /// on what `self` guarantees, e.g. one that indexes a string checked to be
+    /// non-empty, belongs behind [`Check::and`] instead.
     fn or<B, C>(self, b: B) -> Or<Self, B, C>
         where 
             B: Check<T, Self::PostState>,
@@ -29,12 +54,76 @@ pub trait Check<T, PreState>
     {
         Or { a: self, b, _combine: PhantomData }
     }
+
+    fn either<B, C>(self, b: B) -> Either<Self, B, C>
+        where
+            B: Check<T, PreState, PostState = Self::PostState>,
+            C: CombineErrorBuilder<Self::Error, B::Error>
+    {
+        Either { a: self, b, _combine: PhantomData }
+    }
+
+    fn not<F, E>(self, f: F) -> Not<Self, F>
+        where
+            F: Fn(&T) -> E
+    {
+        Not { a: self, f }
+    }
+
+    /// Rewrites the error of this check with `f`.
+    fn map_err<F, E>(self, f: F) -> MapErr<Self, F>
+        where
+            F: Fn(Self::Error) -> E
+    {
+        MapErr { a: self, f }
+    }
+
+    /// Converts the error of this check with [`Into`].
+    fn err_into<E>(self) -> ErrInto<Self, E>
+        where
+            Self::Error: Into<E>
+    {
+        ErrInto { a: self, _err: PhantomData }
+    }
+
+    fn and_parse<P, C>(self, p: P) -> parse::AndParse<Self, P, C>
+        where
+            P: parse::Parse<T, Self::PostState>,
+            C: CombineErrorBuilder<Self::Error, P::Error>
+    {
+        parse::AndParse { a: self, p, _combine: PhantomData }
+    }
+
+    fn par_or<B, C>(self, b: B) -> ParOr<Self, B, C>
+        where
+            B: Check<T, PreState, PostState = Self::PostState>,
+            C: CombineErrorBuilder<Self::Error, B::Error>
+    {
+        ParOr { a: self, b, _combine: PhantomData }
+    }
 }
 
+/// Check that can be evaluated through a shared reference.
+///
+/// [`Check::check`] consumes the checker, so a pipeline has to be rebuilt
+/// for every value. A `Validator` can be built once, stored in a `static`
+/// or an `Arc`, and used for every value (and from several threads when it
+/// is `Sync`).
+pub trait Validator<T, PreState>: Check<T, PreState> {
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>;
+}
+
+/// Result of a check.
+///
+/// Only a passed outcome carries the post-check `State`, as a [`Validated`]
+/// proof that only the checks of this crate can build. A failed outcome
+/// hands the value back in the [`Unverified`] state, so it can never be
+/// mistaken for a value whose checks passed.
 pub enum CheckOutcome<T, State, E> {
-    Passed(CheckState<T, State>),
+    Passed(Validated<T, State>),
     Failed{
-        state: CheckState<T, State>,
+        state: CheckState<T, Unverified>,
         err: E
     },
 }
@@ -61,8 +150,171 @@ impl<T, State, E> CheckOutcome<T, State, E> {
             }
         }
     }
+
+    /// Keeps the typestate of a passed outcome as a [`Validated`] proof.
+    pub fn into_validated(self) -> Result<Validated<T, State>, E> {
+        match self {
+            CheckOutcome::Passed(v) => {
+                Ok(v)
+            }
+            CheckOutcome::Failed{state:_, err} => {
+                Err(err)
+            }
+        }
+    }
+
+    pub fn is_passed(&self) -> bool {
+        matches!(self, CheckOutcome::Passed(_))
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, CheckOutcome::Failed{..})
+    }
+
+    /// Error of a failed outcome.
+    pub fn err(self) -> Option<E> {
+        match self {
+            CheckOutcome::Passed(_) => None,
+            CheckOutcome::Failed{state:_, err} => Some(err),
+        }
+    }
+
+    /// Value carried by the outcome, whether it passed or failed.
+    pub fn value(&self) -> &T {
+        match self {
+            CheckOutcome::Passed(v) => &v.value,
+            CheckOutcome::Failed{state, err:_} => &state.value,
+        }
+    }
+
+    /// Maps the carried value, keeping the error.
+    ///
+    /// The new value has not been checked, so a passed outcome is handed
+    /// back in the [`Unverified`] state.
+    pub fn map_value<U, F>(self, f: F) -> CheckOutcome<U, Unverified, E>
+        where
+            F: FnOnce(T) -> U
+    {
+        match self {
+            CheckOutcome::Passed(v) => {
+                CheckOutcome::Passed(Validated::new(f(v.value)))
+            }
+            CheckOutcome::Failed{state, err} => {
+                CheckOutcome::Failed{ state: CheckState::new(f(state.value)), err }
+            }
+        }
+    }
+
+    pub fn map_err<E2, F>(self, f: F) -> CheckOutcome<T, State, E2>
+        where
+            F: FnOnce(E) -> E2
+    {
+        match self {
+            CheckOutcome::Passed(v) => {
+                CheckOutcome::Passed(v)
+            }
+            CheckOutcome::Failed{state, err} => {
+                CheckOutcome::Failed{ state, err: f(err) }
+            }
+        }
+    }
+
+    /// Runs `f` on a passed outcome, like a [`Check::and`] with [`DefaultCombine`].
+    ///
+    /// [`DefaultCombine`]: crate::cmberr::DefaultCombine
+    pub fn and_then<S2, F>(self, f: F) -> CheckOutcome<T, S2, E>
+        where
+            F: FnOnce(CheckState<T, State>) -> CheckOutcome<T, S2, E>
+    {
+        match self {
+            CheckOutcome::Passed(v) => {
+                f(v.into_state())
+            }
+            CheckOutcome::Failed{state, err} => {
+                CheckOutcome::Failed{ state, err }
+            }
+        }
+    }
+
+    /// Value of a passed outcome, or the value computed by `f` from a failed one.
+    pub fn unwrap_or_else<F>(self, f: F) -> T
+        where
+            F: FnOnce(T, E) -> T
+    {
+        match self {
+            CheckOutcome::Passed(v) => v.value,
+            CheckOutcome::Failed{state, err} => f(state.value, err),
+        }
+    }
+
+    pub fn inspect_err<F>(self, f: F) -> Self
+        where
+            F: FnOnce(&E)
+    {
+        if let CheckOutcome::Failed{state:_, err} = &self {
+            f(err);
+        }
+        self
+    }
+}
+
+impl<T, E> From<Result<T, (T, E)>> for CheckOutcome<T, Unverified, E> {
+    /// Inverse of [`CheckOutcome::to_result_with_data`]. A bare `Ok` proves
+    /// nothing, so the outcome is in the [`Unverified`] state.
+    fn from(r: Result<T, (T, E)>) -> Self {
+        match r {
+            Ok(value) => CheckOutcome::Passed(Validated::new(value)),
+            Err((value, err)) => CheckOutcome::Failed{
+                state: CheckState::new(value),
+                err
+            },
+        }
+    }
+}
+
+impl<T: Clone, State, E: Clone> Clone for CheckOutcome<T, State, E> {
+    fn clone(&self) -> Self {
+        match self {
+            CheckOutcome::Passed(v) => CheckOutcome::Passed(v.clone()),
+            CheckOutcome::Failed{state, err} => CheckOutcome::Failed{
+                state: state.clone(),
+                err: err.clone()
+            },
+        }
+    }
+}
+
+impl<T: fmt::Debug, State, E: fmt::Debug> fmt::Debug for CheckOutcome<T, State, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckOutcome::Passed(v) => {
+                f.debug_tuple("Passed").field(&v.value).finish()
+            }
+            CheckOutcome::Failed{state, err} => {
+                f.debug_struct("Failed")
+                    .field("value", &state.value)
+                    .field("err", err)
+                    .finish()
+            }
+        }
+    }
+}
+
+impl<T: PartialEq, State, E: PartialEq> PartialEq for CheckOutcome<T, State, E> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CheckOutcome::Passed(a), CheckOutcome::Passed(b)) => a == b,
+            (
+                CheckOutcome::Failed{state: sa, err: ea},
+                CheckOutcome::Failed{state: sb, err: eb}
+            ) => sa == sb && ea == eb,
+            _ => false,
+        }
+    }
 }
 
+impl<T: Eq, State, E: Eq> Eq for CheckOutcome<T, State, E> {}
+
 pub struct CheckState<T: Sized, S> 
     where Self: Sized 
 {
@@ -76,12 +328,190 @@ impl<T, S> CheckState<T, S> {
     }
 }
 
+impl<T: Clone, S> Clone for CheckState<T, S> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for CheckState<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CheckState").field("value", &self.value).finish()
+    }
+}
+
+impl<T: PartialEq, S> PartialEq for CheckState<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, S> Eq for CheckState<T, S> {}
+
+/// Proof that `value` passed a check pipeline ending in the state `S`.
+///
+/// A `Validated` is obtained from a passed outcome through
+/// [`CheckOutcome::into_validated`], so downstream functions can demand it
+/// instead of a bare `T`:
+///
+/// ```
+/// use error_combinator::{
+///     check::{check_ref, Check, CheckState, Validated},
+///     cmberr::DefaultCombine,
+///     state::Has,
+///     States,
+/// };
+///
+/// struct User { name: String, age: u32 }
+/// struct NameChecked;
+/// struct AgeChecked;
+///
+/// fn save(user: Validated<&User, States![AgeChecked, NameChecked]>) -> u32 {
+///     user.age
+/// }
+///
+/// // or only require some of the checks, with the traits from `state`
+/// fn greet<S, I>(user: &Validated<&User, S>) -> String
+/// where
+///     S: Has<NameChecked, I>
+/// {
+///     format!("hello {}", user.name)
+/// }
+///
+/// let checker = check_ref::<User, States![], States![NameChecked], _, _>(|u| {
+///         if u.name.is_empty() { Err("name") } else { Ok(()) }
+///     })
+///     .and::<_, DefaultCombine<_>>(
+///         check_ref::<User, _, States![AgeChecked, NameChecked], _, _>(|u| {
+///             if u.age < 150 { Ok(()) } else { Err("age") }
+///         })
+///     );
+///
+/// let user = User { name: "tom".to_string(), age: 20 };
+/// let user = checker.check(CheckState::new(&user)).into_validated().unwrap();
+/// assert_eq!(greet(&user), "hello tom");
+/// assert_eq!(save(user), 20);
+/// ```
+///
+/// A `Validated` has no public constructor, so a passed outcome can only
+/// come from a check that ran. A hand-written check returns `Ok` or `Err`
+/// through [`check_ref`], [`check_noref`] or a [`rules::Rule`], which declare
+/// the transition and build the state once the check passed:
+///
+/// ```compile_fail
+/// use error_combinator::check::{CheckOutcome, Validated};
+///
+/// struct AllChecked;
+///
+/// let forged: CheckOutcome<&str, AllChecked, ()> = CheckOutcome::Passed(Validated::new("x"));
+/// ```
+pub struct Validated<T, S> {
+    value: T,
+    _state: PhantomData<S>
+}
+
+impl<T, S> Validated<T, S> {
+    /// Passing constructor, only for the checks of this crate.
+    pub(crate) fn new(value: T) -> Self {
+        Self { value, _state: PhantomData }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Hands the value to the next check, in the state it was proven in.
+    pub fn into_state(self) -> CheckState<T, S> {
+        CheckState::new(self.value)
+    }
+}
+
+impl<T, S> AsRef<T> for Validated<T, S> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, S> Deref for Validated<T, S> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Clone, S> Clone for Validated<T, S> {
+    fn clone(&self) -> Self {
+        Self { value: self.value.clone(), _state: PhantomData }
+    }
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for Validated<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Validated").field(&self.value).finish()
+    }
+}
+
+impl<T: PartialEq, S> PartialEq for Validated<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, S> Eq for Validated<T, S> {}
+
 pub struct And<A, B, C> {
     a: A,
     b: B,
     _combine: PhantomData<C>
 }
 
+impl<A: Clone, B: Clone, C> Clone for And<A, B, C> {
+    fn clone(&self) -> Self {
+        And { a: self.a.clone(), b: self.b.clone(), _combine: PhantomData }
+    }
+}
+
+fn and_outcome<T, PreState, A, B, C>(
+    value: CheckState<T, PreState>,
+    a: impl FnOnce(CheckState<T, PreState>) -> CheckOutcome<T, A::PostState, A::Error>,
+    b: impl FnOnce(CheckState<T, A::PostState>) -> CheckOutcome<T, B::PostState, B::Error>,
+) -> CheckOutcome<T, B::PostState, C::Out>
+where
+    A: Check<T, PreState>,
+    B: Check<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+
+    match a(value) {
+        CheckOutcome::Passed(v) => {
+            match b(v.into_state()) {
+                CheckOutcome::Passed(vv) => {
+                    // success A and success B
+                    CheckOutcome::Passed(vv)
+                }
+                CheckOutcome::Failed{state, err} => {
+                    // success A and failed B
+                    combine.right(err);
+                    CheckOutcome::Failed{
+                        state: CheckState { value: state.value, _state: PhantomData },
+                        err: combine.finish()
+                    }
+                }
+            }
+        }
+        CheckOutcome::Failed{state, err} => {
+            // failed A
+            combine.left(err);
+            CheckOutcome::Failed{
+                state: CheckState { value: state.value, _state: PhantomData },
+                err: combine.finish()
+            }
+        }
+    }
+}
+
 impl<T, PreState, A, B, C> Check<T, PreState> for And<A, B, C>
 where
     A: Check<T, PreState>,
@@ -94,93 +524,288 @@ where
     fn check(self, value: CheckState<T, PreState>)
         -> CheckOutcome<T, Self::PostState, Self::Error>
     {
-        let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+        let (a, b) = (self.a, self.b);
+        and_outcome::<T, PreState, A, B, C>(value, |v| a.check(v), |v| b.check(v))
+    }
+}
 
-        match self.a.check(value) {
-            CheckOutcome::Passed(v) => {
-                match self.b.check(v){
-                    CheckOutcome::Passed(vv) => {
-                        // success A and success B
-                        CheckOutcome::Passed(vv)
-                    }
-                    CheckOutcome::Failed{state, err} => {
-                        // success A and failed B
-                        combine.right(err);
-                        CheckOutcome::Failed{
-                            state: CheckState { value: state.value, _state: PhantomData },
-                            err: combine.finish()
-                        }
+impl<T, PreState, A, B, C> Validator<T, PreState> for And<A, B, C>
+where
+    A: Validator<T, PreState>,
+    B: Validator<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        and_outcome::<T, PreState, A, B, C>(value, |v| self.a.validate(v), |v| self.b.validate(v))
+    }
+}
+
+pub struct Or<A, B, C> {
+    a: A,
+    b: B,
+    _combine: PhantomData<C>
+}
+
+impl<A: Clone, B: Clone, C> Clone for Or<A, B, C> {
+    fn clone(&self) -> Self {
+        Or { a: self.a.clone(), b: self.b.clone(), _combine: PhantomData }
+    }
+}
+
+fn or_outcome<T, PreState, A, B, C>(
+    value: CheckState<T, PreState>,
+    a: impl FnOnce(CheckState<T, PreState>) -> CheckOutcome<T, A::PostState, A::Error>,
+    b: impl FnOnce(CheckState<T, A::PostState>) -> CheckOutcome<T, B::PostState, B::Error>,
+) -> CheckOutcome<T, B::PostState, C::Out>
+where
+    A: Check<T, PreState>,
+    B: Check<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+
+    match a(value) {
+        CheckOutcome::Passed(v) => {
+            // success A
+            match b(v.into_state()) {
+                CheckOutcome::Passed(vv) => {
+                    // success A and success B
+                    CheckOutcome::Passed(vv)
+                }
+                CheckOutcome::Failed{state, err} => {
+                    // success A and failed B
+                    combine.right(err);
+                    CheckOutcome::Failed{
+                        state: CheckState { value: state.value, _state: PhantomData },
+                        err: combine.finish()
                     }
                 }
             }
-            CheckOutcome::Failed{state, err} => {
-                // failed A
-                combine.left(err);
-                CheckOutcome::Failed{
-                    state: CheckState { value: state.value, _state: PhantomData },
-                    err: combine.finish()
+        }
+        CheckOutcome::Failed{state, err} => {
+            // failed A: B still runs at A's post-state to report its error,
+            // see `Check::or`
+            combine.left(err);
+            match b(CheckState::new(state.value)) {
+                CheckOutcome::Passed(vv) => {
+                    // failed A and success B
+                    CheckOutcome::Failed{
+                        state: CheckState { value: vv.value, _state: PhantomData },
+                        err: combine.finish()
+                    }
+                }
+                CheckOutcome::Failed{state, err} => {
+                    // failed A and failed B
+                    combine.right(err);
+                    CheckOutcome::Failed{
+                        state: CheckState { value: state.value, _state: PhantomData },
+                        err: combine.finish()
+                    }
                 }
             }
         }
     }
 }
 
-pub struct Or<A, B, C> {
+impl<T, PreState, A, B, C> Check<T, PreState> for Or<A, B, C>
+where
+    A: Check<T, PreState>,
+    B: Check<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    type PostState = B::PostState;
+    type Error = C::Out;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let (a, b) = (self.a, self.b);
+        or_outcome::<T, PreState, A, B, C>(value, |v| a.check(v), |v| b.check(v))
+    }
+}
+
+impl<T, PreState, A, B, C> Validator<T, PreState> for Or<A, B, C>
+where
+    A: Validator<T, PreState>,
+    B: Validator<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        or_outcome::<T, PreState, A, B, C>(value, |v| self.a.validate(v), |v| self.b.validate(v))
+    }
+}
+
+/// Parallel version of [`Or`].
+///
+/// `a` runs on a scoped worker thread while `b` runs on the current one, so
+/// expensive independent checks do not wait for each other. Errors are still
+/// combined in the order `a`, `b`. Both sides see the same value, so `T` has
+/// to be `Copy`, which fits borrowed values checked with [`check_ref`].
+/// As with [`Either`], neither side waits for the other, so both start from
+/// the same `PreState` and must arrive at the same `PostState`.
+///
+/// ```compile_fail
+/// use error_combinator::{check::{check_ref, Check}, cmberr::VecCombine};
+///
+/// struct Unchecked;
+/// struct NonEmpty;
+///
+/// fn non_empty(s: &str) -> Result<(), ()> {
+///     if s.is_empty() { Err(()) } else { Ok(()) }
+/// }
+///
+/// // `b` runs next to `a`, so it cannot rely on what `a` checks
+/// let checker = check_ref::<str, Unchecked, NonEmpty, _, _>(non_empty)
+///     .par_or::<_, VecCombine<()>>(check_ref::<str, NonEmpty, NonEmpty, _, _>(non_empty));
+/// ```
+pub struct ParOr<A, B, C> {
     a: A,
     b: B,
     _combine: PhantomData<C>
 }
 
-impl<T, PreState, A, B, C> Check<T, PreState> for Or<A, B, C>
+impl<A: Clone, B: Clone, C> Clone for ParOr<A, B, C> {
+    fn clone(&self) -> Self {
+        ParOr { a: self.a.clone(), b: self.b.clone(), _combine: PhantomData }
+    }
+}
+
+fn par_or_outcome<T, PreState, A, B, C>(
+    value: CheckState<T, PreState>,
+    a: impl FnOnce(CheckState<T, PreState>) -> CheckOutcome<T, A::PostState, A::Error> + Send,
+    b: impl FnOnce(CheckState<T, PreState>) -> CheckOutcome<T, A::PostState, B::Error>,
+) -> CheckOutcome<T, A::PostState, C::Out>
 where
+    T: Copy + Send,
     A: Check<T, PreState>,
-    B: Check<T, A::PostState>,
+    A::Error: Send,
+    B: Check<T, PreState, PostState = A::PostState>,
     C: CombineErrorBuilder<A::Error, B::Error>,
 {
-    type PostState = B::PostState;
+    let value = value.value;
+    let (ra, rb) = thread::scope(|scope| {
+        let ha = scope.spawn(move || a(CheckState::new(value)).to_result_with_data());
+        let rb = b(CheckState::new(value));
+        let ra = match ha.join() {
+            Ok(ra) => ra,
+            Err(e) => panic::resume_unwind(e),
+        };
+        (ra, rb)
+    });
+
+    match (ra, rb) {
+        (Ok(_), CheckOutcome::Passed(vv)) => {
+            // success A and success B
+            CheckOutcome::Passed(vv)
+        }
+        (ra, rb) => {
+            let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+            if let Err((_, err)) = ra {
+                combine.left(err);
+            }
+            let value = match rb {
+                CheckOutcome::Passed(vv) => vv.value,
+                CheckOutcome::Failed{state, err} => {
+                    combine.right(err);
+                    state.value
+                }
+            };
+            CheckOutcome::Failed{
+                state: CheckState::new(value),
+                err: combine.finish()
+            }
+        }
+    }
+}
+
+impl<T, PreState, A, B, C> Check<T, PreState> for ParOr<A, B, C>
+where
+    T: Copy + Send,
+    A: Check<T, PreState> + Send,
+    A::Error: Send,
+    B: Check<T, PreState, PostState = A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    type PostState = A::PostState;
     type Error = C::Out;
 
     fn check(self, value: CheckState<T, PreState>)
         -> CheckOutcome<T, Self::PostState, Self::Error>
     {
-        let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+        let (a, b) = (self.a, self.b);
+        par_or_outcome::<T, PreState, A, B, C>(value, |v| a.check(v), |v| b.check(v))
+    }
+}
 
-        match self.a.check(value) {
-            CheckOutcome::Passed(v) => {
-                // success A
-                match self.b.check(v) {
-                    CheckOutcome::Passed(vv) => {
-                        // success A and success B
-                        CheckOutcome::Passed(vv)
-                    }
-                    CheckOutcome::Failed{state, err} => {
-                        // success A and failed B
-                        combine.right(err);
-                        CheckOutcome::Failed{
-                            state: CheckState { value: state.value, _state: PhantomData },
-                            err: combine.finish()
-                        }
-                    }
+impl<T, PreState, A, B, C> Validator<T, PreState> for ParOr<A, B, C>
+where
+    T: Copy + Send,
+    A: Validator<T, PreState> + Send + Sync,
+    A::Error: Send,
+    B: Validator<T, PreState, PostState = A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let a = &self.a;
+        par_or_outcome::<T, PreState, A, B, C>(value, |v| a.validate(v), |v| self.b.validate(v))
+    }
+}
+
+/// Logical disjunction of two checks.
+///
+/// Unlike [`Or`], which runs both sides and accumulates their errors,
+/// `Either` passes as soon as one branch passes. `b` is only run when `a`
+/// failed, and errors are combined only when both branches failed.
+/// Both branches start from the same `PreState` and must arrive at the same
+/// `PostState`.
+pub struct Either<A, B, C> {
+    a: A,
+    b: B,
+    _combine: PhantomData<C>
+}
+
+impl<A: Clone, B: Clone, C> Clone for Either<A, B, C> {
+    fn clone(&self) -> Self {
+        Either { a: self.a.clone(), b: self.b.clone(), _combine: PhantomData }
+    }
+}
+
+fn either_outcome<T, PreState, A, B, C>(
+    value: CheckState<T, PreState>,
+    a: impl FnOnce(CheckState<T, PreState>) -> CheckOutcome<T, A::PostState, A::Error>,
+    b: impl FnOnce(CheckState<T, PreState>) -> CheckOutcome<T, A::PostState, B::Error>,
+) -> CheckOutcome<T, A::PostState, C::Out>
+where
+    A: Check<T, PreState>,
+    B: Check<T, PreState, PostState = A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    match a(value) {
+        CheckOutcome::Passed(v) => {
+            // success A
+            CheckOutcome::Passed(v)
+        }
+        CheckOutcome::Failed{state, err} => {
+            // failed A
+            match b(CheckState::new(state.value)) {
+                CheckOutcome::Passed(vv) => {
+                    // failed A and success B
+                    CheckOutcome::Passed(vv)
                 }
-            }
-            CheckOutcome::Failed{state, err} => {
-                // failed A
-                combine.left(err);
-                match self.b.check(state) {
-                    CheckOutcome::Passed(vv) => {
-                        // failed A and success B
-                        CheckOutcome::Failed{
-                            state: CheckState { value: vv.value, _state: PhantomData },
-                            err: combine.finish()
-                        }
-                    }
-                    CheckOutcome::Failed{state, err} => {
-                        // failed A and failed B
-                        combine.right(err);
-                        CheckOutcome::Failed{
-                            state: CheckState { value: state.value, _state: PhantomData },
-                            err: combine.finish()
-                        }
+                CheckOutcome::Failed{state, err: err_b} => {
+                    // failed A and failed B
+                    let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+                    combine.left(err);
+                    combine.right(err_b);
+                    CheckOutcome::Failed{
+                        state: CheckState { value: state.value, _state: PhantomData },
+                        err: combine.finish()
                     }
                 }
             }
@@ -188,6 +813,168 @@ where
     }
 }
 
+impl<T, PreState, A, B, C> Check<T, PreState> for Either<A, B, C>
+where
+    A: Check<T, PreState>,
+    B: Check<T, PreState, PostState = A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    type PostState = A::PostState;
+    type Error = C::Out;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let (a, b) = (self.a, self.b);
+        either_outcome::<T, PreState, A, B, C>(value, |v| a.check(v), |v| b.check(v))
+    }
+}
+
+impl<T, PreState, A, B, C> Validator<T, PreState> for Either<A, B, C>
+where
+    A: Validator<T, PreState>,
+    B: Validator<T, PreState, PostState = A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        either_outcome::<T, PreState, A, B, C>(value, |v| self.a.validate(v), |v| self.b.validate(v))
+    }
+}
+
+/// Negation of a check.
+///
+/// A failure of `a` becomes a pass (its error is discarded), and a pass of
+/// `a` becomes a failure whose error is produced by `f` from the value.
+/// The typestate transition of `a` is kept as is.
+#[derive(Clone)]
+pub struct Not<A, F> {
+    a: A,
+    f: F
+}
+
+fn not_outcome<T, PostState, EA, E>(
+    outcome: CheckOutcome<T, PostState, EA>,
+    f: impl Fn(&T) -> E,
+) -> CheckOutcome<T, PostState, E>
+{
+    match outcome {
+        CheckOutcome::Passed(v) => {
+            let err = f(&v.value);
+            CheckOutcome::Failed{
+                state: CheckState::new(v.value),
+                err
+            }
+        }
+        CheckOutcome::Failed{state, err:_} => {
+            CheckOutcome::Passed(Validated::new(state.value))
+        }
+    }
+}
+
+impl<T, PreState, A, F, E> Check<T, PreState> for Not<A, F>
+where
+    A: Check<T, PreState>,
+    F: Fn(&T) -> E,
+{
+    type PostState = A::PostState;
+    type Error = E;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        not_outcome(self.a.check(value), self.f)
+    }
+}
+
+impl<T, PreState, A, F, E> Validator<T, PreState> for Not<A, F>
+where
+    A: Validator<T, PreState>,
+    F: Fn(&T) -> E,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        not_outcome(self.a.validate(value), &self.f)
+    }
+}
+
+/// Check whose error is rewritten by `f`, see [`Check::map_err`].
+///
+/// The typestate transition of `a` is kept as is.
+#[derive(Clone)]
+pub struct MapErr<A, F> {
+    a: A,
+    f: F
+}
+
+impl<T, PreState, A, F, E> Check<T, PreState> for MapErr<A, F>
+where
+    A: Check<T, PreState>,
+    F: Fn(A::Error) -> E,
+{
+    type PostState = A::PostState;
+    type Error = E;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self.a.check(value).map_err(self.f)
+    }
+}
+
+impl<T, PreState, A, F, E> Validator<T, PreState> for MapErr<A, F>
+where
+    A: Validator<T, PreState>,
+    F: Fn(A::Error) -> E,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self.a.validate(value).map_err(&self.f)
+    }
+}
+
+/// Check whose error is converted with [`Into`], see [`Check::err_into`].
+pub struct ErrInto<A, E> {
+    a: A,
+    _err: PhantomData<fn() -> E>
+}
+
+impl<A: Clone, E> Clone for ErrInto<A, E> {
+    fn clone(&self) -> Self {
+        ErrInto { a: self.a.clone(), _err: PhantomData }
+    }
+}
+
+impl<T, PreState, A, E> Check<T, PreState> for ErrInto<A, E>
+where
+    A: Check<T, PreState>,
+    A::Error: Into<E>,
+{
+    type PostState = A::PostState;
+    type Error = E;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self.a.check(value).map_err(Into::into)
+    }
+}
+
+impl<T, PreState, A, E> Validator<T, PreState> for ErrInto<A, E>
+where
+    A: Validator<T, PreState>,
+    A::Error: Into<E>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self.a.validate(value).map_err(Into::into)
+    }
+}
+
 impl<T, PreState, PostState, F, E> Check<T, PreState> for F
 where
     F: Fn(CheckState<T, PreState>) -> CheckOutcome<T, PostState, E>,
@@ -202,15 +989,52 @@ where
     }
 }
 
-pub fn check_ref<'a, T: ?Sized, Pre, Post, E, F>(
-    f: F
-) -> impl Check<&'a T, Pre, PostState = Post, Error = E>
+impl<T, PreState, PostState, F, E> Validator<T, PreState> for F
+where
+    F: Fn(CheckState<T, PreState>) -> CheckOutcome<T, PostState, E>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self(value)
+    }
+}
+
+/// Check built from a `Fn(&T) -> Result<(), E>` on a borrowed value, see [`check_ref`].
+pub struct CheckRef<T: ?Sized, Pre, Post, F> {
+    f: F,
+    _state: PhantomData<fn(&T, Pre) -> Post>
+}
+
+impl<T: ?Sized, Pre, Post, F: Clone> Clone for CheckRef<T, Pre, Post, F> {
+    fn clone(&self) -> Self {
+        CheckRef { f: self.f.clone(), _state: PhantomData }
+    }
+}
+
+impl<'a, T: ?Sized, Pre, Post, E, F> Check<&'a T, Pre> for CheckRef<T, Pre, Post, F>
 where
     F: Fn(&T) -> Result<(), E>,
 {
-    move |state: CheckState<&'a T, Pre>| {
-        match f(state.value) {
-            Ok(()) => CheckOutcome::Passed(CheckState::new(state.value)),
+    type PostState = Post;
+    type Error = E;
+
+    fn check(self, state: CheckState<&'a T, Pre>)
+        -> CheckOutcome<&'a T, Self::PostState, Self::Error>
+    {
+        self.validate(state)
+    }
+}
+
+impl<'a, T: ?Sized, Pre, Post, E, F> Validator<&'a T, Pre> for CheckRef<T, Pre, Post, F>
+where
+    F: Fn(&T) -> Result<(), E>,
+{
+    fn validate(&self, state: CheckState<&'a T, Pre>)
+        -> CheckOutcome<&'a T, Self::PostState, Self::Error>
+    {
+        match (self.f)(state.value) {
+            Ok(()) => CheckOutcome::Passed(Validated::new(state.value)),
             Err(e) => CheckOutcome::Failed {
                 state: CheckState::new(state.value),
                 err: e,
@@ -219,18 +1043,52 @@ where
     }
 }
 
-
-pub fn check_noref<T: Sized, Pre, Post, E, F>( 
+pub fn check_ref<T: ?Sized, Pre, Post, E, F>(
     f: F
-) -> impl Check<T, Pre, PostState = Post, Error = E>
-where 
+) -> CheckRef<T, Pre, Post, F>
+where
+    F: Fn(&T) -> Result<(), E>,
+{
+    CheckRef { f, _state: PhantomData }
+}
+
+/// Check built from a `Fn(&T) -> Result<(), E>` on an owned value, see [`check_noref`].
+pub struct CheckNoref<T, Pre, Post, F> {
+    f: F,
+    _state: PhantomData<fn(&T, Pre) -> Post>
+}
+
+impl<T, Pre, Post, F: Clone> Clone for CheckNoref<T, Pre, Post, F> {
+    fn clone(&self) -> Self {
+        CheckNoref { f: self.f.clone(), _state: PhantomData }
+    }
+}
+
+impl<T, Pre, Post, E, F> Check<T, Pre> for CheckNoref<T, Pre, Post, F>
+where
     F: Fn(&T) -> Result<(), E>,
 {
-    move |state: CheckState<T, Pre>| {
-          match f(&state.value) {
+    type PostState = Post;
+    type Error = E;
+
+    fn check(self, state: CheckState<T, Pre>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self.validate(state)
+    }
+}
+
+impl<T, Pre, Post, E, F> Validator<T, Pre> for CheckNoref<T, Pre, Post, F>
+where
+    F: Fn(&T) -> Result<(), E>,
+{
+    fn validate(&self, state: CheckState<T, Pre>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+          match (self.f)(&state.value) {
              Ok(_v) => {
                  CheckOutcome::Passed(
-                     CheckState::new(state.value)
+                     Validated::new(state.value)
                  )
              },
              Err(e) => CheckOutcome::Failed {
@@ -240,3 +1098,12 @@ where
          }
     }
 }
+
+pub fn check_noref<T: Sized, Pre, Post, E, F>( 
+    f: F
+) -> CheckNoref<T, Pre, Post, F>
+where 
+    F: Fn(&T) -> Result<(), E>,
+{
+    CheckNoref { f, _state: PhantomData }
+}
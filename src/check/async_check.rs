@@ -0,0 +1,193 @@
+use std::{
+    future::{self, Future},
+    marker::PhantomData
+};
+
+use super::{
+    Check,
+    CheckOutcome,
+    CheckState
+};
+use crate::cmberr::{
+    CombineError,
+    CombineErrorBuilder
+};
+
+/// Asynchronous counterpart of [`Check`], for checks that need I/O.
+///
+/// The returned futures do not depend on any runtime, so they can be driven
+/// by any executor. Every `async fn(CheckState<T, Pre>) -> CheckOutcome<T, Post, E>`
+/// (or closure returning such a future) implements `AsyncCheck`, and
+/// synchronous checks are lifted with [`lift`].
+pub trait AsyncCheck<T, PreState>
+    where Self: Sized
+{
+    type PostState;
+    type Error;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> impl Future<Output = CheckOutcome<T, Self::PostState, Self::Error>>;
+
+    fn and<B, C>(self, b: B) -> AsyncAnd<Self, B, C>
+        where
+            B: AsyncCheck<T, Self::PostState>,
+            C: CombineErrorBuilder<Self::Error, B::Error>
+    {
+        AsyncAnd { a: self, b, _combine: PhantomData }
+    }
+
+    /// Awaits `b` after `self` and accumulates the errors of both. Like
+    /// [`Check::or`], `b` also runs at the post-state of `self` when `self`
+    /// failed.
+    fn or<B, C>(self, b: B) -> AsyncOr<Self, B, C>
+        where
+            B: AsyncCheck<T, Self::PostState>,
+            C: CombineErrorBuilder<Self::Error, B::Error>
+    {
+        AsyncOr { a: self, b, _combine: PhantomData }
+    }
+}
+
+impl<T, PreState, PostState, F, Fut, E> AsyncCheck<T, PreState> for F
+where
+    F: Fn(CheckState<T, PreState>) -> Fut,
+    Fut: Future<Output = CheckOutcome<T, PostState, E>>,
+{
+    type PostState = PostState;
+    type Error = E;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> impl Future<Output = CheckOutcome<T, Self::PostState, Self::Error>>
+    {
+        self(value)
+    }
+}
+
+/// Synchronous [`Check`] used as an [`AsyncCheck`], see [`lift`].
+#[derive(Clone)]
+pub struct Lift<C> {
+    inner: C
+}
+
+/// Lifts a synchronous check into an [`AsyncCheck`] that is ready immediately.
+pub fn lift<C>(check: C) -> Lift<C> {
+    Lift { inner: check }
+}
+
+impl<T, PreState, C> AsyncCheck<T, PreState> for Lift<C>
+where
+    C: Check<T, PreState>,
+{
+    type PostState = C::PostState;
+    type Error = C::Error;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> impl Future<Output = CheckOutcome<T, Self::PostState, Self::Error>>
+    {
+        future::ready(self.inner.check(value))
+    }
+}
+
+/// Asynchronous version of [`And`](super::And): `b` is awaited only if `a` passed.
+pub struct AsyncAnd<A, B, C> {
+    a: A,
+    b: B,
+    _combine: PhantomData<C>
+}
+
+impl<T, PreState, A, B, C> AsyncCheck<T, PreState> for AsyncAnd<A, B, C>
+where
+    A: AsyncCheck<T, PreState>,
+    B: AsyncCheck<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    type PostState = B::PostState;
+    type Error = C::Out;
+
+    async fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+
+        match self.a.check(value).await {
+            CheckOutcome::Passed(v) => {
+                match self.b.check(v.into_state()).await {
+                    CheckOutcome::Passed(vv) => {
+                        // success A and success B
+                        CheckOutcome::Passed(vv)
+                    }
+                    CheckOutcome::Failed{state, err} => {
+                        // success A and failed B
+                        combine.right(err);
+                        CheckOutcome::Failed{ state, err: combine.finish() }
+                    }
+                }
+            }
+            CheckOutcome::Failed{state, err} => {
+                // failed A
+                combine.left(err);
+                CheckOutcome::Failed{ state, err: combine.finish() }
+            }
+        }
+    }
+}
+
+/// Asynchronous version of [`Or`](super::Or): both sides are awaited in order
+/// and their errors accumulated.
+pub struct AsyncOr<A, B, C> {
+    a: A,
+    b: B,
+    _combine: PhantomData<C>
+}
+
+impl<T, PreState, A, B, C> AsyncCheck<T, PreState> for AsyncOr<A, B, C>
+where
+    A: AsyncCheck<T, PreState>,
+    B: AsyncCheck<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    type PostState = B::PostState;
+    type Error = C::Out;
+
+    async fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+
+        match self.a.check(value).await {
+            CheckOutcome::Passed(v) => {
+                // success A
+                match self.b.check(v.into_state()).await {
+                    CheckOutcome::Passed(vv) => {
+                        // success A and success B
+                        CheckOutcome::Passed(vv)
+                    }
+                    CheckOutcome::Failed{state, err} => {
+                        // success A and failed B
+                        combine.right(err);
+                        CheckOutcome::Failed{ state, err: combine.finish() }
+                    }
+                }
+            }
+            CheckOutcome::Failed{state, err} => {
+                // failed A: B still runs at A's post-state to report its
+                // error, see `Check::or`
+                combine.left(err);
+                match self.b.check(CheckState::new(state.value)).await {
+                    CheckOutcome::Passed(vv) => {
+                        // failed A and success B
+                        CheckOutcome::Failed{
+                            state: CheckState::new(vv.value),
+                            err: combine.finish()
+                        }
+                    }
+                    CheckOutcome::Failed{state, err} => {
+                        // failed A and failed B
+                        combine.right(err);
+                        CheckOutcome::Failed{ state, err: combine.finish() }
+                    }
+                }
+            }
+        }
+    }
+}
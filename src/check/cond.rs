@@ -0,0 +1,139 @@
+use super::{
+    Check,
+    CheckOutcome,
+    CheckState,
+    Validated,
+    Validator
+};
+
+/// Check that only runs when a predicate on the value holds, see [`when`] and [`unless`].
+#[derive(Clone)]
+pub struct When<P, C> {
+    pred: P,
+    check: C,
+    expect: bool
+}
+
+impl<T, PreState, P, C> Check<T, PreState> for When<P, C>
+where
+    P: Fn(&T) -> bool,
+    C: Check<T, PreState>,
+{
+    type PostState = C::PostState;
+    type Error = C::Error;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        if (self.pred)(&value.value) == self.expect {
+            self.check.check(value)
+        } else {
+            CheckOutcome::Passed(Validated::new(value.value))
+        }
+    }
+}
+
+impl<T, PreState, P, C> Validator<T, PreState> for When<P, C>
+where
+    P: Fn(&T) -> bool,
+    C: Validator<T, PreState>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        if (self.pred)(&value.value) == self.expect {
+            self.check.validate(value)
+        } else {
+            CheckOutcome::Passed(Validated::new(value.value))
+        }
+    }
+}
+
+/// Runs `check` only if `pred` holds for the value, and passes otherwise.
+///
+/// A skipped check still moves to the post-state of `check`: the rule is
+/// conditional, and it has been considered either way.
+///
+/// ```
+/// use error_combinator::check::{check_ref, cond::when, Check, CheckState};
+///
+/// struct Address { country: String, zip: String }
+///
+/// fn check_zip5(a: &Address) -> Result<(), &'static str> {
+///     if a.zip.len() == 5 { Ok(()) } else { Err("zip") }
+/// }
+///
+/// let paris = Address { country: "FR".to_string(), zip: "750001".to_string() };
+/// let zip = when(|a: &&Address| a.country == "US", check_ref::<_, (), (), _, _>(check_zip5));
+/// assert!(zip.check(CheckState::new(&paris)).is_passed());
+/// ```
+pub fn when<T, P, C>(pred: P, check: C) -> When<P, C>
+where
+    P: Fn(&T) -> bool,
+{
+    When { pred, check, expect: true }
+}
+
+/// Runs `check` only if `pred` does not hold for the value, and passes otherwise.
+pub fn unless<T, P, C>(pred: P, check: C) -> When<P, C>
+where
+    P: Fn(&T) -> bool,
+{
+    When { pred, check, expect: false }
+}
+
+/// Runs one of two checks depending on a predicate, see [`if_else`].
+#[derive(Clone)]
+pub struct IfElse<P, A, B> {
+    pred: P,
+    a: A,
+    b: B
+}
+
+impl<T, PreState, P, A, B> Check<T, PreState> for IfElse<P, A, B>
+where
+    P: Fn(&T) -> bool,
+    A: Check<T, PreState>,
+    B: Check<T, PreState, PostState = A::PostState, Error = A::Error>,
+{
+    type PostState = A::PostState;
+    type Error = A::Error;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        if (self.pred)(&value.value) {
+            self.a.check(value)
+        } else {
+            self.b.check(value)
+        }
+    }
+}
+
+impl<T, PreState, P, A, B> Validator<T, PreState> for IfElse<P, A, B>
+where
+    P: Fn(&T) -> bool,
+    A: Validator<T, PreState>,
+    B: Validator<T, PreState, PostState = A::PostState, Error = A::Error>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        if (self.pred)(&value.value) {
+            self.a.validate(value)
+        } else {
+            self.b.validate(value)
+        }
+    }
+}
+
+/// Runs `a` if `pred` holds for the value and `b` otherwise.
+///
+/// Both branches must end in the same state with the same error type;
+/// use [`Check::map_err`] to unify the errors.
+pub fn if_else<T, P, A, B>(pred: P, a: A, b: B) -> IfElse<P, A, B>
+where
+    P: Fn(&T) -> bool,
+{
+    IfElse { pred, a, b }
+}
@@ -0,0 +1,203 @@
+use super::{
+    Check,
+    CheckNoref,
+    CheckOutcome,
+    CheckRef,
+    CheckState,
+    Validated,
+    Validator,
+    check_noref,
+    check_ref
+};
+use crate::state::Unverified;
+
+/// Object-safe counterpart of [`Check`] for pipelines built at runtime.
+///
+/// Every [`Validator`] implements `DynCheck`. The post-check state is
+/// erased: a passed outcome is handed back in the pre-check state `S`,
+/// since which checks ran is only known at runtime.
+pub trait DynCheck<T, E, S = Unverified> {
+    fn check_dyn(&self, value: CheckState<T, S>) -> CheckOutcome<T, S, E>;
+}
+
+impl<T, E, S, C> DynCheck<T, E, S> for C
+where
+    C: Validator<T, S, Error = E>,
+{
+    fn check_dyn(&self, value: CheckState<T, S>) -> CheckOutcome<T, S, E> {
+        match self.validate(value) {
+            CheckOutcome::Passed(v) => CheckOutcome::Passed(Validated::new(v.value)),
+            CheckOutcome::Failed{state, err} => CheckOutcome::Failed{state, err},
+        }
+    }
+}
+
+/// [`check_ref`] whose post-check state is its pre-check state, as
+/// expected by [`DynCheck`].
+pub fn dyn_ref<T: ?Sized, S, E, F>(f: F) -> CheckRef<T, S, S, F>
+where
+    F: Fn(&T) -> Result<(), E>,
+{
+    check_ref(f)
+}
+
+/// [`check_noref`] whose post-check state is its pre-check state, as
+/// expected by [`DynCheck`].
+pub fn dyn_noref<T, S, E, F>(f: F) -> CheckNoref<T, S, S, F>
+where
+    F: Fn(&T) -> Result<(), E>,
+{
+    check_noref(f)
+}
+
+/// Boxed [`DynCheck`] that can still be combined like any other [`Check`].
+///
+/// The check must be `Send + Sync`, so that a pipeline assembled at runtime
+/// can be shared between threads, e.g. in an `Arc`.
+pub struct BoxedCheck<T, E, S = Unverified> {
+    inner: Box<dyn DynCheck<T, E, S> + Send + Sync>
+}
+
+impl<T, E, S> BoxedCheck<T, E, S> {
+    pub fn new<C>(check: C) -> Self
+    where
+        C: DynCheck<T, E, S> + Send + Sync + 'static,
+    {
+        Self { inner: Box::new(check) }
+    }
+}
+
+impl<T, E, S> Check<T, S> for BoxedCheck<T, E, S> {
+    type PostState = S;
+    type Error = E;
+
+    fn check(self, value: CheckState<T, S>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self.inner.check_dyn(value)
+    }
+}
+
+impl<T, E, S> Validator<T, S> for BoxedCheck<T, E, S> {
+    fn validate(&self, value: CheckState<T, S>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self.inner.check_dyn(value)
+    }
+}
+
+/// Runs every check and passes only if all of them passed.
+///
+/// All errors are collected in the order the checks were added.
+pub struct AllOf<T, E, S = Unverified> {
+    checks: Vec<BoxedCheck<T, E, S>>
+}
+
+/// Passes as soon as one check passes.
+///
+/// Errors are only reported when every check failed. An empty `AnyOf`
+/// fails with no errors, as no check passed.
+pub struct AnyOf<T, E, S = Unverified> {
+    checks: Vec<BoxedCheck<T, E, S>>
+}
+
+macro_rules! impl_check_list {
+    ($name:ident) => {
+        impl<T, E, S> $name<T, E, S> {
+            pub fn new() -> Self {
+                Self { checks: Vec::new() }
+            }
+
+            pub fn push<C>(&mut self, check: C)
+            where
+                C: DynCheck<T, E, S> + Send + Sync + 'static,
+            {
+                self.checks.push(BoxedCheck::new(check));
+            }
+
+            pub fn with<C>(mut self, check: C) -> Self
+            where
+                C: DynCheck<T, E, S> + Send + Sync + 'static,
+            {
+                self.push(check);
+                self
+            }
+
+            pub fn len(&self) -> usize {
+                self.checks.len()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.checks.is_empty()
+            }
+        }
+
+        impl<T, E, S> Default for $name<T, E, S> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T, E, S> From<Vec<BoxedCheck<T, E, S>>> for $name<T, E, S> {
+            fn from(checks: Vec<BoxedCheck<T, E, S>>) -> Self {
+                Self { checks }
+            }
+        }
+
+        impl<T, E, S> Check<T, S> for $name<T, E, S> {
+            type PostState = S;
+            type Error = Vec<E>;
+
+            fn check(self, value: CheckState<T, S>)
+                -> CheckOutcome<T, Self::PostState, Self::Error>
+            {
+                self.validate(value)
+            }
+        }
+    };
+}
+
+impl_check_list!(AllOf);
+impl_check_list!(AnyOf);
+
+impl<T, E, S> Validator<T, S> for AllOf<T, E, S> {
+    fn validate(&self, value: CheckState<T, S>) -> CheckOutcome<T, S, Vec<E>> {
+        let mut value = value.value;
+        let mut errs = Vec::new();
+        for check in &self.checks {
+            match check.inner.check_dyn(CheckState::new(value)) {
+                CheckOutcome::Passed(v) => {
+                    value = v.value;
+                }
+                CheckOutcome::Failed{state, err} => {
+                    value = state.value;
+                    errs.push(err);
+                }
+            }
+        }
+        if errs.is_empty() {
+            CheckOutcome::Passed(Validated::new(value))
+        } else {
+            CheckOutcome::Failed{ state: CheckState::new(value), err: errs }
+        }
+    }
+}
+
+impl<T, E, S> Validator<T, S> for AnyOf<T, E, S> {
+    fn validate(&self, value: CheckState<T, S>) -> CheckOutcome<T, S, Vec<E>> {
+        let mut value = value.value;
+        let mut errs = Vec::new();
+        for check in &self.checks {
+            match check.inner.check_dyn(CheckState::new(value)) {
+                CheckOutcome::Passed(v) => {
+                    return CheckOutcome::Passed(v);
+                }
+                CheckOutcome::Failed{state, err} => {
+                    value = state.value;
+                    errs.push(err);
+                }
+            }
+        }
+        CheckOutcome::Failed{ state: CheckState::new(value), err: errs }
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    collections::{BTreeSet, HashSet, LinkedList, VecDeque},
+    marker::PhantomData
+};
+
+use super::{
+    Check,
+    CheckOutcome,
+    CheckState,
+    Validated,
+    Validator,
+    field::FieldError
+};
+use crate::cmberr::{
+    CombineError,
+    CombineErrorBuilder
+};
+
+/// Collections whose elements [`each`] checks, by reference.
+///
+/// Index `i` in the reported [`FieldError`] paths is the position of the
+/// element in iteration order, which for sets is their iteration order.
+pub trait Elements {
+    type Item;
+
+    fn elements(&self) -> impl Iterator<Item = &Self::Item>;
+}
+
+impl<X> Elements for [X] {
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter()
+    }
+}
+
+impl<X, const N: usize> Elements for [X; N] {
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter()
+    }
+}
+
+impl<X> Elements for Vec<X> {
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter()
+    }
+}
+
+impl<X> Elements for Box<[X]> {
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter()
+    }
+}
+
+impl<X> Elements for VecDeque<X> {
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter()
+    }
+}
+
+impl<X> Elements for LinkedList<X> {
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter()
+    }
+}
+
+impl<X, S> Elements for HashSet<X, S> {
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter()
+    }
+}
+
+impl<X> Elements for BTreeSet<X> {
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter()
+    }
+}
+
+impl<L: Elements + ?Sized> Elements for &L {
+    type Item = L::Item;
+
+    fn elements(&self) -> impl Iterator<Item = &L::Item> {
+        (**self).elements()
+    }
+}
+
+/// Cloneable iterator over element references, so that an iterator such as
+/// `map.values()` can be checked with [`each`].
+#[derive(Debug, Clone)]
+pub struct IterElements<'a, X, I> {
+    iter: I,
+    _item: PhantomData<&'a X>
+}
+
+impl<'a, X, I> IterElements<'a, X, I>
+where
+    I: Iterator<Item = &'a X> + Clone,
+{
+    pub fn new(iter: I) -> Self {
+        IterElements { iter, _item: PhantomData }
+    }
+}
+
+impl<'a, X, I> Elements for IterElements<'a, X, I>
+where
+    I: Iterator<Item = &'a X> + Clone,
+{
+    type Item = X;
+
+    fn elements(&self) -> impl Iterator<Item = &X> {
+        self.iter.clone().map(|x| -> &X { x })
+    }
+}
+
+/// Check run on every element of a collection, see [`each`] and [`each_fail_fast`].
+pub struct Each<V, C> {
+    check: V,
+    fail_fast: bool,
+    _combine: PhantomData<C>
+}
+
+impl<V: Clone, C> Clone for Each<V, C> {
+    fn clone(&self) -> Self {
+        Each { check: self.check.clone(), fail_fast: self.fail_fast, _combine: PhantomData }
+    }
+}
+
+fn each_outcome<T, PreState, V, C, P, E, O>(
+    each: &Each<V, C>,
+    value: CheckState<T, PreState>,
+) -> CheckOutcome<T, P, O>
+where
+    T: Elements,
+    V: for<'x> Validator<&'x T::Item, PreState, PostState = P, Error = E>,
+    C: CombineErrorBuilder<FieldError<E>, FieldError<E>, Out = O>
+        + CombineErrorBuilder<O, FieldError<E>, Out = O>,
+{
+    let mut errs: Option<O> = None;
+
+    for (i, item) in value.value.elements().enumerate() {
+        let err = match each.check.validate(CheckState::new(item)) {
+            CheckOutcome::Passed(_) => continue,
+            CheckOutcome::Failed{state:_, err} => FieldError::new(i, err),
+        };
+
+        errs = Some(match errs {
+            None => {
+                // first failed element
+                let mut combine = <C as CombineErrorBuilder<FieldError<E>, FieldError<E>>>::build();
+                combine.left(err);
+                combine.finish()
+            }
+            Some(acc) => {
+                let mut combine = <C as CombineErrorBuilder<O, FieldError<E>>>::build();
+                combine.left(acc);
+                combine.right(err);
+                combine.finish()
+            }
+        });
+
+        if each.fail_fast {
+            break;
+        }
+    }
+
+    match errs {
+        None => CheckOutcome::Passed(Validated::new(value.value)),
+        Some(err) => CheckOutcome::Failed{ state: CheckState::new(value.value), err },
+    }
+}
+
+impl<T, PreState, V, C, P, E, O> Check<T, PreState> for Each<V, C>
+where
+    T: Elements,
+    V: for<'x> Validator<&'x T::Item, PreState, PostState = P, Error = E>,
+    C: CombineErrorBuilder<FieldError<E>, FieldError<E>, Out = O>
+        + CombineErrorBuilder<O, FieldError<E>, Out = O>,
+{
+    type PostState = P;
+    type Error = O;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        each_outcome(&self, value)
+    }
+}
+
+impl<T, PreState, V, C, P, E, O> Validator<T, PreState> for Each<V, C>
+where
+    T: Elements,
+    V: for<'x> Validator<&'x T::Item, PreState, PostState = P, Error = E>,
+    C: CombineErrorBuilder<FieldError<E>, FieldError<E>, Out = O>
+        + CombineErrorBuilder<O, FieldError<E>, Out = O>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        each_outcome(self, value)
+    }
+}
+
+/// Runs `check` on every element and combines the errors of all failed
+/// elements with `C`, each tagged with its index.
+///
+/// The collection (`Vec<X>`, `&[X]`, `VecDeque<X>`, `BTreeSet<X>`, ...,
+/// see [`Elements`]) is iterated by reference, so `check` runs on `&X`. With `DefaultCombine` only the error
+/// of the last failed element is kept; use [`each_fail_fast`] for the first.
+///
+/// ```
+/// use error_combinator::{
+///     check::{check_ref, each::each, field::FieldError, Check, CheckState},
+///     cmberr::VecCombine,
+/// };
+///
+/// let check_positive = check_ref::<i32, (), (), _, _>(|n| {
+///     if 0 < *n { Ok(()) } else { Err("positive") }
+/// });
+/// let checker = each::<_, VecCombine<FieldError<&str>>>(check_positive);
+///
+/// let errs = checker.check(CheckState::new(vec![1, -2, 3, 0])).err().unwrap();
+/// let paths: Vec<String> = errs.iter().map(|e| e.path.to_string()).collect();
+/// assert_eq!(paths, ["[1]", "[3]"]);
+/// ```
+pub fn each<V, C>(check: V) -> Each<V, C> {
+    Each { check, fail_fast: false, _combine: PhantomData }
+}
+
+/// Like [`each`], but stops at the first failed element.
+pub fn each_fail_fast<V, C>(check: V) -> Each<V, C> {
+    Each { check, fail_fast: true, _combine: PhantomData }
+}
@@ -0,0 +1,469 @@
+use std::{
+    collections::BTreeMap,
+    fmt
+};
+
+use super::{
+    Check,
+    CheckOutcome,
+    CheckState,
+    Validated,
+    Validator
+};
+
+/// One step of a [`FieldPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+/// Location of a value inside a nested structure, e.g. `address.zip` or
+/// `items[2].name`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FieldPath {
+    segments: Vec<PathSegment>
+}
+
+impl FieldPath {
+    /// Empty path, pointing at the value itself.
+    pub fn new() -> Self {
+        FieldPath { segments: Vec::new() }
+    }
+
+    /// Parses a dotted path. `[n]` suffixes become [`PathSegment::Index`],
+    /// so `"items[2].name"` has the segments `items`, `2` and `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics on a malformed path, such as `"a[1"` or `"a..b"`. Paths given
+    /// to [`field`], [`nested`] and [`cross`] are meant to be literals; use
+    /// [`FieldPath::try_parse`] for a path that comes from input.
+    pub fn parse(path: &str) -> Self {
+        match FieldPath::try_parse(path) {
+            Ok(p) => p,
+            Err(e) => panic!("{}: {:?}", e, path),
+        }
+    }
+
+    /// Parses a dotted path like [`FieldPath::parse`], failing on a
+    /// malformed one. The empty string is the empty path.
+    pub fn try_parse(path: &str) -> Result<Self, PathParseError> {
+        let mut segments = Vec::new();
+        if path.is_empty() {
+            return Ok(FieldPath { segments });
+        }
+        let mut offset = 0;
+        for part in path.split('.') {
+            parse_part(part, offset, &mut segments)?;
+            offset += part.len() + 1;
+        }
+        Ok(FieldPath { segments })
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn push(&mut self, segment: PathSegment) {
+        self.segments.push(segment);
+    }
+
+    /// Puts `prefix` in front of this path.
+    pub fn prepend(&mut self, prefix: &FieldPath) {
+        self.segments.splice(0..0, prefix.segments.iter().cloned());
+    }
+}
+
+/// `name[i][j]...`, the part of a path between two dots.
+fn parse_part(part: &str, offset: usize, segments: &mut Vec<PathSegment>)
+    -> Result<(), PathParseError>
+{
+    if part.is_empty() {
+        return Err(PathParseError { offset });
+    }
+    let (name, mut rest) = part.split_at(part.find('[').unwrap_or(part.len()));
+    if let Some(i) = name.find(']') {
+        return Err(PathParseError { offset: offset + i });
+    }
+    if !name.is_empty() {
+        segments.push(PathSegment::Field(name.to_string()));
+    }
+    let mut at = offset + name.len();
+    while !rest.is_empty() {
+        let error = PathParseError { offset: at };
+        let inner = rest.strip_prefix('[').ok_or(error)?;
+        let close = inner.find(']').ok_or(error)?;
+        let index = &inner[..close];
+        if index.is_empty() || index.contains('[') {
+            return Err(error);
+        }
+        match index.parse::<usize>() {
+            Ok(i) => segments.push(PathSegment::Index(i)),
+            Err(_) => segments.push(PathSegment::Field(index.to_string())),
+        }
+        rest = &inner[close + 1..];
+        at += close + 2;
+    }
+    Ok(())
+}
+
+/// Error of [`FieldPath::try_parse`]: the path is malformed at byte `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathParseError {
+    pub offset: usize
+}
+
+impl fmt::Display for PathParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed field path at offset {}", self.offset)
+    }
+}
+
+impl std::error::Error for PathParseError {}
+
+/// Same as [`FieldPath::parse`], so it panics on a malformed path.
+impl From<&str> for FieldPath {
+    fn from(path: &str) -> Self {
+        FieldPath::parse(path)
+    }
+}
+
+impl From<usize> for FieldPath {
+    fn from(index: usize) -> Self {
+        FieldPath { segments: vec![PathSegment::Index(index)] }
+    }
+}
+
+impl From<PathSegment> for FieldPath {
+    fn from(segment: PathSegment) -> Self {
+        FieldPath { segments: vec![segment] }
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error of a check that ran on the value at `path`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError<E> {
+    pub path: FieldPath,
+    pub err: E
+}
+
+impl<E> FieldError<E> {
+    pub fn new(path: impl Into<FieldPath>, err: E) -> Self {
+        FieldError { path: path.into(), err }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for FieldError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.err)
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for FieldError<E> {}
+
+/// Error of a check that ran on several fields at once, see [`cross`].
+///
+/// `paths` lists every involved field; the first one is the field the
+/// error is reported on, e.g. `confirm` for a password confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossFieldError<E> {
+    pub paths: Vec<FieldPath>,
+    pub err: E
+}
+
+impl<E> CrossFieldError<E> {
+    /// Field the error is reported on.
+    pub fn primary(&self) -> Option<&FieldPath> {
+        self.paths.first()
+    }
+
+    /// One [`FieldError`] per involved field, e.g. to feed [`by_path`].
+    pub fn into_field_errors(self) -> Vec<FieldError<E>>
+        where
+            E: Clone
+    {
+        let err = self.err;
+        self.paths.into_iter().map(|path| FieldError { path, err: err.clone() }).collect()
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for CrossFieldError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, path) in self.paths.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", path)?;
+        }
+        write!(f, ": {}", self.err)
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for CrossFieldError<E> {}
+
+/// Groups field errors by their path, e.g. for an API response.
+pub fn by_path<E>(errs: impl IntoIterator<Item = FieldError<E>>) -> BTreeMap<String, Vec<E>> {
+    let mut map: BTreeMap<String, Vec<E>> = BTreeMap::new();
+    for e in errs {
+        map.entry(e.path.to_string()).or_default().push(e.err);
+    }
+    map
+}
+
+/// Errors that already carry a [`FieldPath`] and can be moved under a parent path.
+pub trait Nest {
+    fn nest(self, prefix: &FieldPath) -> Self;
+}
+
+impl<E> Nest for FieldError<E> {
+    fn nest(mut self, prefix: &FieldPath) -> Self {
+        self.path.prepend(prefix);
+        self
+    }
+}
+
+impl<E> Nest for CrossFieldError<E> {
+    fn nest(mut self, prefix: &FieldPath) -> Self {
+        for path in &mut self.paths {
+            path.prepend(prefix);
+        }
+        self
+    }
+}
+
+impl<N: Nest> Nest for Vec<N> {
+    fn nest(self, prefix: &FieldPath) -> Self {
+        self.into_iter().map(|e| e.nest(prefix)).collect()
+    }
+}
+
+/// Check on a projected sub-value, see [`field`].
+#[derive(Clone)]
+pub struct Field<P, C> {
+    path: FieldPath,
+    proj: P,
+    check: C
+}
+
+fn field_outcome<T, W, PostState, E, R>(
+    value: T,
+    outcome: CheckOutcome<W, PostState, E>,
+    f: impl FnOnce(E) -> R,
+) -> CheckOutcome<T, PostState, R>
+{
+    match outcome {
+        CheckOutcome::Passed(_) => {
+            CheckOutcome::Passed(Validated::new(value))
+        }
+        CheckOutcome::Failed{state:_, err} => {
+            CheckOutcome::Failed{ state: CheckState::new(value), err: f(err) }
+        }
+    }
+}
+
+impl<T, W, PreState, P, C> Check<T, PreState> for Field<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+    C: Check<W, PreState>,
+{
+    type PostState = C::PostState;
+    type Error = FieldError<C::Error>;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let outcome = self.check.check(CheckState::new((self.proj)(value.value)));
+        let path = self.path;
+        field_outcome(value.value, outcome, |err| FieldError { path, err })
+    }
+}
+
+impl<T, W, PreState, P, C> Validator<T, PreState> for Field<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+    C: Validator<W, PreState>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let outcome = self.check.validate(CheckState::new((self.proj)(value.value)));
+        field_outcome(value.value, outcome, |err| FieldError { path: self.path.clone(), err })
+    }
+}
+
+/// Runs `check` on the sub-value returned by `proj` and tags its error with `path`.
+///
+/// ```
+/// use error_combinator::check::{
+///     check_ref, field::{field, FieldError}, Check, CheckState,
+/// };
+///
+/// struct Address { zip: String }
+/// struct User { address: Address }
+///
+/// let check_zip = check_ref::<str, (), (), _, _>(|zip| {
+///     if zip.len() == 5 { Ok(()) } else { Err("zip") }
+/// });
+/// let checker = field("address.zip", |u: &User| u.address.zip.as_str(), check_zip);
+///
+/// let user = User { address: Address { zip: "123".to_string() } };
+/// let err: FieldError<&str> = checker.check(CheckState::new(&user)).err().unwrap();
+/// assert_eq!(err.path.to_string(), "address.zip");
+/// ```
+///
+/// The value is handed to `proj` by copy, so `T` is usually a reference.
+/// When `check` already reports [`FieldError`]s, use [`nested`] instead so
+/// that the paths are extended rather than wrapped twice.
+pub fn field<T, W, P, C>(path: impl Into<FieldPath>, proj: P, check: C) -> Field<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+{
+    Field { path: path.into(), proj, check }
+}
+
+/// Check on a projected sub-value whose errors carry their own paths, see [`nested`].
+#[derive(Clone)]
+pub struct Nested<P, C> {
+    path: FieldPath,
+    proj: P,
+    check: C
+}
+
+impl<T, W, PreState, P, C> Check<T, PreState> for Nested<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+    C: Check<W, PreState>,
+    C::Error: Nest,
+{
+    type PostState = C::PostState;
+    type Error = C::Error;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let outcome = self.check.check(CheckState::new((self.proj)(value.value)));
+        field_outcome(value.value, outcome, |err| err.nest(&self.path))
+    }
+}
+
+impl<T, W, PreState, P, C> Validator<T, PreState> for Nested<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+    C: Validator<W, PreState>,
+    C::Error: Nest,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let outcome = self.check.validate(CheckState::new((self.proj)(value.value)));
+        field_outcome(value.value, outcome, |err| err.nest(&self.path))
+    }
+}
+
+/// Runs `check` on the sub-value returned by `proj` and puts `path` in
+/// front of the paths of its [`FieldError`]s.
+pub fn nested<T, W, P, C>(path: impl Into<FieldPath>, proj: P, check: C) -> Nested<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+{
+    Nested { path: path.into(), proj, check }
+}
+
+/// Check on several projected sub-values at once, see [`cross`].
+#[derive(Clone)]
+pub struct Cross<P, C> {
+    paths: Vec<FieldPath>,
+    proj: P,
+    check: C
+}
+
+impl<T, W, PreState, P, C> Check<T, PreState> for Cross<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+    C: Check<W, PreState>,
+{
+    type PostState = C::PostState;
+    type Error = CrossFieldError<C::Error>;
+
+    fn check(self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let outcome = self.check.check(CheckState::new((self.proj)(value.value)));
+        let paths = self.paths;
+        field_outcome(value.value, outcome, |err| CrossFieldError { paths, err })
+    }
+}
+
+impl<T, W, PreState, P, C> Validator<T, PreState> for Cross<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+    C: Validator<W, PreState>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let outcome = self.check.validate(CheckState::new((self.proj)(value.value)));
+        field_outcome(value.value, outcome, |err| CrossFieldError { paths: self.paths.clone(), err })
+    }
+}
+
+/// Runs `check` on several fields projected together by `proj` and tags
+/// its error with all of `paths`, the first one being the reported field.
+///
+/// ```
+/// use error_combinator::check::{check_noref, field::cross, Check, CheckState};
+///
+/// struct User { password: String, confirm: String }
+///
+/// let check_same = check_noref::<(&str, &str), (), (), _, _>(|(a, b)| {
+///     if a == b { Ok(()) } else { Err("mismatch") }
+/// });
+/// let checker = cross(
+///     ["confirm", "password"],
+///     |u: &User| (u.password.as_str(), u.confirm.as_str()),
+///     check_same,
+/// );
+///
+/// let user = User { password: "secret".to_string(), confirm: "secert".to_string() };
+/// let err = checker.check(CheckState::new(&user)).err().unwrap();
+/// assert_eq!(err.primary().unwrap().to_string(), "confirm");
+/// ```
+///
+/// `check` sees the tuple and decides the transition, so its post-state
+/// can mark every involved field as checked, e.g. with
+/// `Mark<Mark<S, Password>, Confirm>` from [`crate::state`].
+pub fn cross<T, W, P, C, I>(paths: I, proj: P, check: C) -> Cross<P, C>
+where
+    T: Copy,
+    P: Fn(T) -> W,
+    I: IntoIterator,
+    I::Item: Into<FieldPath>,
+{
+    Cross { paths: paths.into_iter().map(Into::into).collect(), proj, check }
+}
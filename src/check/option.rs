@@ -0,0 +1,111 @@
+use super::{
+    Check,
+    CheckOutcome,
+    CheckState,
+    Validated,
+    Validator
+};
+
+/// Check on an `Option` that passes on `None`, see [`optional`].
+#[derive(Clone)]
+pub struct Optional<C> {
+    check: C
+}
+
+/// Check on an `Option` that fails on `None`, see [`required`].
+#[derive(Clone)]
+pub struct Required<C, E> {
+    check: C,
+    err: E
+}
+
+fn some_outcome<W, PostState, E>(
+    outcome: CheckOutcome<W, PostState, E>,
+) -> CheckOutcome<Option<W>, PostState, E>
+{
+    match outcome {
+        CheckOutcome::Passed(v) => {
+            CheckOutcome::Passed(Validated::new(Some(v.value)))
+        }
+        CheckOutcome::Failed{state, err} => {
+            CheckOutcome::Failed{ state: CheckState::new(Some(state.value)), err }
+        }
+    }
+}
+
+impl<W, PreState, C> Check<Option<W>, PreState> for Optional<C>
+where
+    C: Check<W, PreState>,
+{
+    type PostState = C::PostState;
+    type Error = C::Error;
+
+    fn check(self, value: CheckState<Option<W>, PreState>)
+        -> CheckOutcome<Option<W>, Self::PostState, Self::Error>
+    {
+        match value.value {
+            Some(w) => some_outcome(self.check.check(CheckState::new(w))),
+            None => CheckOutcome::Passed(Validated::new(None)),
+        }
+    }
+}
+
+impl<W, PreState, C> Validator<Option<W>, PreState> for Optional<C>
+where
+    C: Validator<W, PreState>,
+{
+    fn validate(&self, value: CheckState<Option<W>, PreState>)
+        -> CheckOutcome<Option<W>, Self::PostState, Self::Error>
+    {
+        match value.value {
+            Some(w) => some_outcome(self.check.validate(CheckState::new(w))),
+            None => CheckOutcome::Passed(Validated::new(None)),
+        }
+    }
+}
+
+impl<W, PreState, C> Check<Option<W>, PreState> for Required<C, C::Error>
+where
+    C: Check<W, PreState>,
+{
+    type PostState = C::PostState;
+    type Error = C::Error;
+
+    fn check(self, value: CheckState<Option<W>, PreState>)
+        -> CheckOutcome<Option<W>, Self::PostState, Self::Error>
+    {
+        match value.value {
+            Some(w) => some_outcome(self.check.check(CheckState::new(w))),
+            None => CheckOutcome::Failed{ state: CheckState::new(None), err: self.err },
+        }
+    }
+}
+
+impl<W, PreState, C> Validator<Option<W>, PreState> for Required<C, C::Error>
+where
+    C: Validator<W, PreState>,
+    C::Error: Clone,
+{
+    fn validate(&self, value: CheckState<Option<W>, PreState>)
+        -> CheckOutcome<Option<W>, Self::PostState, Self::Error>
+    {
+        match value.value {
+            Some(w) => some_outcome(self.check.validate(CheckState::new(w))),
+            None => CheckOutcome::Failed{ state: CheckState::new(None), err: self.err.clone() },
+        }
+    }
+}
+
+/// Runs `check` on the inner value of `Some` and passes on `None`.
+///
+/// Both cases move to the post-state of `check`, so later checks know the
+/// value was considered. Use with a projection such as
+/// `|u: &User| u.nickname.as_deref()` to check optional fields.
+pub fn optional<C>(check: C) -> Optional<C> {
+    Optional { check }
+}
+
+/// Runs `check` on the inner value of `Some` and fails with `err` on `None`.
+pub fn required<C, E>(err: E, check: C) -> Required<C, E> {
+    Required { check, err }
+}
@@ -0,0 +1,496 @@
+use std::{
+    marker::PhantomData,
+    str::FromStr
+};
+
+use super::{
+    Check,
+    CheckOutcome,
+    CheckState,
+    Validated,
+    Validator
+};
+use crate::cmberr::{
+    CombineError,
+    CombineErrorBuilder
+};
+use crate::state::Unverified;
+
+/// Stage of a pipeline that turns a `T` into another value type.
+///
+/// `Output` is the parsed value, on which the following checks are typed.
+/// A failure always hands back the original input, even when it happens in
+/// a check that runs after the value was parsed.
+pub trait Parse<T, PreState>
+    where Self: Sized
+{
+    type Output;
+    type PostState;
+    type Error;
+
+    fn parse(self, value: CheckState<T, PreState>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>;
+
+    /// Runs `b` on the parsed value if parsing passed (stops at the first failure).
+    fn and<B, C>(self, b: B) -> ParseAnd<Self, B, C>
+        where
+            B: Check<Self::Output, Self::PostState>,
+            C: CombineErrorBuilder<Self::Error, B::Error>
+    {
+        ParseAnd { a: self, b, _combine: PhantomData }
+    }
+
+    /// Runs `b` on the parsed value and accumulates its error with those of
+    /// the checks before it, like [`Check::or`].
+    ///
+    /// `b` needs a parsed value, so it does not run when parsing failed.
+    fn or<B, C>(self, b: B) -> ParseOr<Self, B, C>
+        where
+            B: Check<Self::Output, Self::PostState>,
+            C: CombineErrorBuilder<Self::Error, B::Error>
+    {
+        ParseOr { a: self, b, _combine: PhantomData }
+    }
+}
+
+/// Parse stage that can be evaluated through a shared reference, like
+/// [`Validator`] for checks.
+pub trait ParseValidator<T, PreState>: Parse<T, PreState> {
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>;
+}
+
+/// Result of a [`Parse`] stage.
+pub enum ParseOutcome<T, U, State, E> {
+    Passed{
+        input: T,
+        state: Validated<U, State>
+    },
+    /// The value was parsed, but a check on the parsed value failed.
+    Rejected{
+        input: T,
+        state: CheckState<U, Unverified>,
+        err: E
+    },
+    /// The value could not be parsed, or a check before parsing failed.
+    Failed{
+        state: CheckState<T, Unverified>,
+        err: E
+    },
+}
+
+impl<T, U, State, E> ParseOutcome<T, U, State, E> {
+    pub fn to_result(self) -> Result<U, E> {
+        match self {
+            ParseOutcome::Passed{input:_, state} => {
+                Ok(state.value)
+            }
+            ParseOutcome::Rejected{input:_, state:_, err} | ParseOutcome::Failed{state:_, err} => {
+                Err(err)
+            }
+        }
+    }
+
+    /// Like [`ParseOutcome::to_result`], but keeps the original input on failure.
+    pub fn to_result_with_data(self) -> Result<U, (T, E)> {
+        match self {
+            ParseOutcome::Passed{input:_, state} => {
+                Ok(state.value)
+            }
+            ParseOutcome::Rejected{input, state:_, err} => {
+                Err((input, err))
+            }
+            ParseOutcome::Failed{state, err} => {
+                Err((state.value, err))
+            }
+        }
+    }
+
+    pub fn into_validated(self) -> Result<Validated<U, State>, E> {
+        match self {
+            ParseOutcome::Passed{input:_, state} => {
+                Ok(state)
+            }
+            ParseOutcome::Rejected{input:_, state:_, err} | ParseOutcome::Failed{state:_, err} => {
+                Err(err)
+            }
+        }
+    }
+
+    pub fn is_passed(&self) -> bool {
+        matches!(self, ParseOutcome::Passed{..})
+    }
+
+    fn map_err<E2>(self, f: impl FnOnce(E) -> E2) -> ParseOutcome<T, U, State, E2> {
+        match self {
+            ParseOutcome::Passed{input, state} => ParseOutcome::Passed{input, state},
+            ParseOutcome::Rejected{input, state, err} => ParseOutcome::Rejected{input, state, err: f(err)},
+            ParseOutcome::Failed{state, err} => ParseOutcome::Failed{state, err: f(err)},
+        }
+    }
+}
+
+fn parsed_outcome<T, U, Post, E>(input: T, r: Result<U, E>) -> ParseOutcome<T, U, Post, E> {
+    match r {
+        Ok(parsed) => ParseOutcome::Passed{
+            input,
+            state: Validated::new(parsed)
+        },
+        Err(err) => ParseOutcome::Failed{
+            state: CheckState::new(input),
+            err
+        },
+    }
+}
+
+/// Parse stage built from a `Fn(T) -> Result<U, E>`, see [`parse_with`].
+pub struct ParseWith<T, Pre, Post, F> {
+    f: F,
+    _state: PhantomData<fn(T, Pre) -> Post>
+}
+
+impl<T, Pre, Post, F: Clone> Clone for ParseWith<T, Pre, Post, F> {
+    fn clone(&self) -> Self {
+        ParseWith { f: self.f.clone(), _state: PhantomData }
+    }
+}
+
+impl<T, Pre, Post, U, E, F> Parse<T, Pre> for ParseWith<T, Pre, Post, F>
+where
+    T: Clone,
+    F: Fn(T) -> Result<U, E>,
+{
+    type Output = U;
+    type PostState = Post;
+    type Error = E;
+
+    fn parse(self, value: CheckState<T, Pre>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>
+    {
+        self.validate(value)
+    }
+}
+
+impl<T, Pre, Post, U, E, F> ParseValidator<T, Pre> for ParseWith<T, Pre, Post, F>
+where
+    T: Clone,
+    F: Fn(T) -> Result<U, E>,
+{
+    fn validate(&self, value: CheckState<T, Pre>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>
+    {
+        let r = (self.f)(value.value.clone());
+        parsed_outcome(value.value, r)
+    }
+}
+
+/// Parses the value with `f`. The input is cloned so that it can still be
+/// reported if a later stage fails.
+pub fn parse_with<T, Pre, Post, U, E, F>(f: F) -> ParseWith<T, Pre, Post, F>
+where
+    T: Clone,
+    F: Fn(T) -> Result<U, E>,
+{
+    ParseWith { f, _state: PhantomData }
+}
+
+/// Parse stage built from [`FromStr`], see [`parse_from_str`].
+pub struct FromStrParse<U, Pre, Post> {
+    _state: PhantomData<fn(Pre) -> (U, Post)>
+}
+
+impl<U, Pre, Post> Clone for FromStrParse<U, Pre, Post> {
+    fn clone(&self) -> Self {
+        FromStrParse { _state: PhantomData }
+    }
+}
+
+impl<'a, U, Pre, Post> Parse<&'a str, Pre> for FromStrParse<U, Pre, Post>
+where
+    U: FromStr,
+{
+    type Output = U;
+    type PostState = Post;
+    type Error = U::Err;
+
+    fn parse(self, value: CheckState<&'a str, Pre>)
+        -> ParseOutcome<&'a str, Self::Output, Self::PostState, Self::Error>
+    {
+        self.validate(value)
+    }
+}
+
+impl<'a, U, Pre, Post> ParseValidator<&'a str, Pre> for FromStrParse<U, Pre, Post>
+where
+    U: FromStr,
+{
+    fn validate(&self, value: CheckState<&'a str, Pre>)
+        -> ParseOutcome<&'a str, Self::Output, Self::PostState, Self::Error>
+    {
+        parsed_outcome(value.value, value.value.parse::<U>())
+    }
+}
+
+/// Parses a `&str` into `U` with [`str::parse`].
+pub fn parse_from_str<U: FromStr, Pre, Post>() -> FromStrParse<U, Pre, Post> {
+    FromStrParse { _state: PhantomData }
+}
+
+/// Check followed by a parse stage, see [`Check::and_parse`].
+pub struct AndParse<A, P, C> {
+    pub(super) a: A,
+    pub(super) p: P,
+    pub(super) _combine: PhantomData<C>
+}
+
+impl<A: Clone, P: Clone, C> Clone for AndParse<A, P, C> {
+    fn clone(&self) -> Self {
+        AndParse { a: self.a.clone(), p: self.p.clone(), _combine: PhantomData }
+    }
+}
+
+fn and_parse_outcome<T, PreState, A, P, C>(
+    value: CheckState<T, PreState>,
+    a: impl FnOnce(CheckState<T, PreState>) -> CheckOutcome<T, A::PostState, A::Error>,
+    p: impl FnOnce(CheckState<T, A::PostState>) -> ParseOutcome<T, P::Output, P::PostState, P::Error>,
+) -> ParseOutcome<T, P::Output, P::PostState, C::Out>
+where
+    A: Check<T, PreState>,
+    P: Parse<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, P::Error>,
+{
+    let mut combine = <C as CombineErrorBuilder<A::Error, P::Error>>::build();
+
+    match a(value) {
+        CheckOutcome::Passed(v) => {
+            match p(v.into_state()) {
+                ParseOutcome::Passed{input, state} => {
+                    // success A and success P
+                    ParseOutcome::Passed{input, state}
+                }
+                outcome => {
+                    // success A and failed P
+                    outcome.map_err(|err| {
+                        combine.right(err);
+                        combine.finish()
+                    })
+                }
+            }
+        }
+        CheckOutcome::Failed{state, err} => {
+            // failed A
+            combine.left(err);
+            ParseOutcome::Failed{ state, err: combine.finish() }
+        }
+    }
+}
+
+impl<T, PreState, A, P, C> Parse<T, PreState> for AndParse<A, P, C>
+where
+    A: Check<T, PreState>,
+    P: Parse<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, P::Error>,
+{
+    type Output = P::Output;
+    type PostState = P::PostState;
+    type Error = C::Out;
+
+    fn parse(self, value: CheckState<T, PreState>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>
+    {
+        let (a, p) = (self.a, self.p);
+        and_parse_outcome::<T, PreState, A, P, C>(value, |v| a.check(v), |v| p.parse(v))
+    }
+}
+
+impl<T, PreState, A, P, C> ParseValidator<T, PreState> for AndParse<A, P, C>
+where
+    A: Validator<T, PreState>,
+    P: ParseValidator<T, A::PostState>,
+    C: CombineErrorBuilder<A::Error, P::Error>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>
+    {
+        and_parse_outcome::<T, PreState, A, P, C>(value, |v| self.a.validate(v), |v| self.p.validate(v))
+    }
+}
+
+/// Parse stage followed by a check on the parsed value, see [`Parse::and`].
+pub struct ParseAnd<A, B, C> {
+    a: A,
+    b: B,
+    _combine: PhantomData<C>
+}
+
+impl<A: Clone, B: Clone, C> Clone for ParseAnd<A, B, C> {
+    fn clone(&self) -> Self {
+        ParseAnd { a: self.a.clone(), b: self.b.clone(), _combine: PhantomData }
+    }
+}
+
+fn parse_and_outcome<T, PreState, A, B, C>(
+    value: CheckState<T, PreState>,
+    a: impl FnOnce(CheckState<T, PreState>) -> ParseOutcome<T, A::Output, A::PostState, A::Error>,
+    b: impl FnOnce(CheckState<A::Output, A::PostState>) -> CheckOutcome<A::Output, B::PostState, B::Error>,
+) -> ParseOutcome<T, A::Output, B::PostState, C::Out>
+where
+    A: Parse<T, PreState>,
+    B: Check<A::Output, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+
+    match a(value) {
+        ParseOutcome::Passed{input, state} => {
+            match b(state.into_state()) {
+                CheckOutcome::Passed(vv) => {
+                    // success A and success B
+                    ParseOutcome::Passed{input, state: vv}
+                }
+                CheckOutcome::Failed{state, err} => {
+                    // success A and failed B
+                    combine.right(err);
+                    ParseOutcome::Rejected{ input, state, err: combine.finish() }
+                }
+            }
+        }
+        ParseOutcome::Rejected{input, state, err} => {
+            // failed A after parsing
+            combine.left(err);
+            ParseOutcome::Rejected{ input, state, err: combine.finish() }
+        }
+        ParseOutcome::Failed{state, err} => {
+            // failed A
+            combine.left(err);
+            ParseOutcome::Failed{ state, err: combine.finish() }
+        }
+    }
+}
+
+impl<T, PreState, A, B, C> Parse<T, PreState> for ParseAnd<A, B, C>
+where
+    A: Parse<T, PreState>,
+    B: Check<A::Output, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    type Output = A::Output;
+    type PostState = B::PostState;
+    type Error = C::Out;
+
+    fn parse(self, value: CheckState<T, PreState>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>
+    {
+        let (a, b) = (self.a, self.b);
+        parse_and_outcome::<T, PreState, A, B, C>(value, |v| a.parse(v), |v| b.check(v))
+    }
+}
+
+impl<T, PreState, A, B, C> ParseValidator<T, PreState> for ParseAnd<A, B, C>
+where
+    A: ParseValidator<T, PreState>,
+    B: Validator<A::Output, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>
+    {
+        parse_and_outcome::<T, PreState, A, B, C>(value, |v| self.a.validate(v), |v| self.b.validate(v))
+    }
+}
+
+/// Parse stage followed by a check on the parsed value whose error is
+/// accumulated, see [`Parse::or`].
+pub struct ParseOr<A, B, C> {
+    a: A,
+    b: B,
+    _combine: PhantomData<C>
+}
+
+impl<A: Clone, B: Clone, C> Clone for ParseOr<A, B, C> {
+    fn clone(&self) -> Self {
+        ParseOr { a: self.a.clone(), b: self.b.clone(), _combine: PhantomData }
+    }
+}
+
+fn parse_or_outcome<T, PreState, A, B, C>(
+    value: CheckState<T, PreState>,
+    a: impl FnOnce(CheckState<T, PreState>) -> ParseOutcome<T, A::Output, A::PostState, A::Error>,
+    b: impl FnOnce(CheckState<A::Output, A::PostState>) -> CheckOutcome<A::Output, B::PostState, B::Error>,
+) -> ParseOutcome<T, A::Output, B::PostState, C::Out>
+where
+    A: Parse<T, PreState>,
+    B: Check<A::Output, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    let mut combine = <C as CombineErrorBuilder<A::Error, B::Error>>::build();
+
+    match a(value) {
+        ParseOutcome::Passed{input, state} => {
+            match b(state.into_state()) {
+                CheckOutcome::Passed(vv) => {
+                    // success A and success B
+                    ParseOutcome::Passed{input, state: vv}
+                }
+                CheckOutcome::Failed{state, err} => {
+                    // success A and failed B
+                    combine.right(err);
+                    ParseOutcome::Rejected{ input, state, err: combine.finish() }
+                }
+            }
+        }
+        ParseOutcome::Rejected{input, state, err} => {
+            // failed A after parsing: B still runs at A's post-state to
+            // report its error, see `Check::or`
+            combine.left(err);
+            let state = match b(CheckState::new(state.value)) {
+                CheckOutcome::Passed(vv) => {
+                    // failed A and success B
+                    CheckState::new(vv.value)
+                }
+                CheckOutcome::Failed{state, err} => {
+                    // failed A and failed B
+                    combine.right(err);
+                    state
+                }
+            };
+            ParseOutcome::Rejected{ input, state, err: combine.finish() }
+        }
+        ParseOutcome::Failed{state, err} => {
+            // failed A before a value was parsed, B cannot run
+            combine.left(err);
+            ParseOutcome::Failed{ state, err: combine.finish() }
+        }
+    }
+}
+
+impl<T, PreState, A, B, C> Parse<T, PreState> for ParseOr<A, B, C>
+where
+    A: Parse<T, PreState>,
+    B: Check<A::Output, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    type Output = A::Output;
+    type PostState = B::PostState;
+    type Error = C::Out;
+
+    fn parse(self, value: CheckState<T, PreState>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>
+    {
+        let (a, b) = (self.a, self.b);
+        parse_or_outcome::<T, PreState, A, B, C>(value, |v| a.parse(v), |v| b.check(v))
+    }
+}
+
+impl<T, PreState, A, B, C> ParseValidator<T, PreState> for ParseOr<A, B, C>
+where
+    A: ParseValidator<T, PreState>,
+    B: Validator<A::Output, A::PostState>,
+    C: CombineErrorBuilder<A::Error, B::Error>,
+{
+    fn validate(&self, value: CheckState<T, PreState>)
+        -> ParseOutcome<T, Self::Output, Self::PostState, Self::Error>
+    {
+        parse_or_outcome::<T, PreState, A, B, C>(value, |v| self.a.validate(v), |v| self.b.validate(v))
+    }
+}
@@ -0,0 +1,136 @@
+//! Ready-made checks for common rules.
+//!
+//! Every constructor returns a [`Rule`], which keeps the state it receives.
+//! The string rules take the error to report; the others report their own
+//! structured error, such as [`net::NetError`] or [`url::UrlError`]. Use [`Rule::mark`] to record the
+//! rule in a state set from [`crate::state`].
+
+use std::marker::PhantomData;
+
+use super::{
+    Check,
+    CheckOutcome,
+    CheckState,
+    Validated,
+    Validator
+};
+use crate::state::{
+    Mark,
+    Set
+};
+
+pub mod collection;
+pub mod email;
+pub mod net;
+pub mod numeric;
+mod punycode;
+pub mod string;
+pub mod url;
+
+/// Check on a `T` built from a `Fn(&T) -> Result<(), E>` that keeps the state `S`.
+///
+/// `T` and `S` are usually inferred from the pipeline the rule is used in;
+/// the string rules accept any `T: AsRef<str>`, e.g. `&str` or `String`.
+pub struct Rule<T, F, S> {
+    f: F,
+    _value: PhantomData<fn(&T, S) -> S>
+}
+
+impl<T, F: Clone, S> Clone for Rule<T, F, S> {
+    fn clone(&self) -> Self {
+        Rule { f: self.f.clone(), _value: PhantomData }
+    }
+}
+
+impl<T, F, S> Rule<T, F, S> {
+    pub fn new<E>(f: F) -> Self
+        where
+            F: Fn(&T) -> Result<(), E>
+    {
+        Rule { f, _value: PhantomData }
+    }
+
+    /// Records `Tag` in the state once the rule passed.
+    pub fn mark<Tag>(self) -> Marked<Self, Tag> {
+        Marked { check: self, _tag: PhantomData }
+    }
+}
+
+fn rule_outcome<T, S, E>(value: T, r: Result<(), E>) -> CheckOutcome<T, S, E> {
+    match r {
+        Ok(()) => CheckOutcome::Passed(Validated::new(value)),
+        Err(err) => CheckOutcome::Failed{ state: CheckState::new(value), err },
+    }
+}
+
+impl<T, S, E, F> Check<T, S> for Rule<T, F, S>
+where
+    F: Fn(&T) -> Result<(), E>,
+{
+    type PostState = S;
+    type Error = E;
+
+    fn check(self, value: CheckState<T, S>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        self.validate(value)
+    }
+}
+
+impl<T, S, E, F> Validator<T, S> for Rule<T, F, S>
+where
+    F: Fn(&T) -> Result<(), E>,
+{
+    fn validate(&self, value: CheckState<T, S>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        let r = (self.f)(&value.value);
+        rule_outcome(value.value, r)
+    }
+}
+
+/// Check that records `Tag` in the state when it passes, see [`Rule::mark`].
+pub struct Marked<C, Tag> {
+    check: C,
+    _tag: PhantomData<fn() -> Tag>
+}
+
+impl<C: Clone, Tag> Clone for Marked<C, Tag> {
+    fn clone(&self) -> Self {
+        Marked { check: self.check.clone(), _tag: PhantomData }
+    }
+}
+
+fn marked_outcome<T, S, Post, E>(outcome: CheckOutcome<T, S, E>) -> CheckOutcome<T, Post, E> {
+    match outcome {
+        CheckOutcome::Passed(v) => CheckOutcome::Passed(Validated::new(v.value)),
+        CheckOutcome::Failed{state, err} => CheckOutcome::Failed{ state, err },
+    }
+}
+
+impl<T, S, C, Tag> Check<T, S> for Marked<C, Tag>
+where
+    C: Check<T, S, PostState = S>,
+    S: Set<Tag>,
+{
+    type PostState = Mark<S, Tag>;
+    type Error = C::Error;
+
+    fn check(self, value: CheckState<T, S>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        marked_outcome(self.check.check(value))
+    }
+}
+
+impl<T, S, C, Tag> Validator<T, S> for Marked<C, Tag>
+where
+    C: Validator<T, S, PostState = S>,
+    S: Set<Tag>,
+{
+    fn validate(&self, value: CheckState<T, S>)
+        -> CheckOutcome<T, Self::PostState, Self::Error>
+    {
+        marked_outcome(self.check.validate(value))
+    }
+}
@@ -0,0 +1,320 @@
+//! Rules on whole collections: `Vec`, slices, arrays, sets and maps.
+//!
+//! Sequence rules (`unique`, `sorted`, `contains`) work on any
+//! `AsRef<[T]>`; key rules work on `HashMap` and `BTreeMap`. To check each
+//! element instead, see [`crate::check::each`].
+//!
+//! ```
+//! use error_combinator::{
+//!     check::{
+//!         rules::collection::{max_items, unique, CollectionError},
+//!         Check, CheckState,
+//!     },
+//!     cmberr::DefaultCombine,
+//! };
+//!
+//! let checker = max_items(10).and::<_, DefaultCombine<CollectionError>>(unique());
+//! let r = checker.check(CheckState::<_, ()>::new(vec![1, 2, 1]));
+//! assert!(r.is_failed());
+//! ```
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque, hash_map::Entry},
+    fmt,
+    hash::{BuildHasher, Hash}
+};
+
+use super::Rule;
+
+/// Collections whose number of items can be counted.
+pub trait Len {
+    fn item_count(&self) -> usize;
+}
+
+impl<T> Len for [T] {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> Len for [T; N] {
+    fn item_count(&self) -> usize {
+        N
+    }
+}
+
+impl<T> Len for Vec<T> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Len for VecDeque<T> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, H> Len for HashSet<T, H> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Len for BTreeSet<T> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, H> Len for HashMap<K, V, H> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> Len for BTreeMap<K, V> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<C: Len + ?Sized> Len for &C {
+    fn item_count(&self) -> usize {
+        (**self).item_count()
+    }
+}
+
+/// Maps whose keys can be looked up, for [`required_keys`] and [`no_extra_keys`].
+pub trait Keys {
+    type Key;
+
+    fn has_key(&self, key: &Self::Key) -> bool;
+
+    fn key_iter(&self) -> impl Iterator<Item = &Self::Key>;
+}
+
+impl<K: Eq + Hash, V, H: BuildHasher> Keys for HashMap<K, V, H> {
+    type Key = K;
+
+    fn has_key(&self, key: &K) -> bool {
+        self.contains_key(key)
+    }
+
+    fn key_iter(&self) -> impl Iterator<Item = &K> {
+        self.keys()
+    }
+}
+
+impl<K: Ord, V> Keys for BTreeMap<K, V> {
+    type Key = K;
+
+    fn has_key(&self, key: &K) -> bool {
+        self.contains_key(key)
+    }
+
+    fn key_iter(&self) -> impl Iterator<Item = &K> {
+        self.keys()
+    }
+}
+
+impl<C: Keys + ?Sized> Keys for &C {
+    type Key = C::Key;
+
+    fn has_key(&self, key: &C::Key) -> bool {
+        (**self).has_key(key)
+    }
+
+    fn key_iter(&self) -> impl Iterator<Item = &C::Key> {
+        (**self).key_iter()
+    }
+}
+
+/// Error of a rule on the items of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionError {
+    TooFewItems{
+        min: usize,
+        len: usize
+    },
+    TooManyItems{
+        max: usize,
+        len: usize
+    },
+    /// The item at `index` repeats the one at `first`.
+    Duplicate{
+        first: usize,
+        index: usize
+    },
+    /// The item at `index` is less than the one before it.
+    NotSorted{
+        index: usize
+    },
+    MissingItem,
+}
+
+impl fmt::Display for CollectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectionError::TooFewItems{min, len} => {
+                write!(f, "must have at least {} items, got {}", min, len)
+            }
+            CollectionError::TooManyItems{max, len} => {
+                write!(f, "must have at most {} items, got {}", max, len)
+            }
+            CollectionError::Duplicate{first, index} => {
+                write!(f, "item {} duplicates item {}", index, first)
+            }
+            CollectionError::NotSorted{index} => {
+                write!(f, "item {} is out of order", index)
+            }
+            CollectionError::MissingItem => write!(f, "must contain the required item"),
+        }
+    }
+}
+
+impl std::error::Error for CollectionError {}
+
+/// Error of a rule on the keys of a map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeysError<K> {
+    Missing(Vec<K>),
+    Extra(Vec<K>),
+}
+
+impl<K: fmt::Debug> fmt::Display for KeysError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeysError::Missing(keys) => write!(f, "missing keys {:?}", keys),
+            KeysError::Extra(keys) => write!(f, "unexpected keys {:?}", keys),
+        }
+    }
+}
+
+impl<K: fmt::Debug> std::error::Error for KeysError<K> {}
+
+type KeysResult<K> = Result<(), KeysError<K>>;
+
+/// Fails unless the collection has at least `min` items.
+pub fn min_items<V: Len, S>(min: usize)
+    -> Rule<V, impl Fn(&V) -> Result<(), CollectionError> + Clone, S>
+{
+    Rule::new(move |v: &V| {
+        let len = v.item_count();
+        if min <= len { Ok(()) } else { Err(CollectionError::TooFewItems{ min, len }) }
+    })
+}
+
+/// Fails if the collection has more than `max` items.
+pub fn max_items<V: Len, S>(max: usize)
+    -> Rule<V, impl Fn(&V) -> Result<(), CollectionError> + Clone, S>
+{
+    Rule::new(move |v: &V| {
+        let len = v.item_count();
+        if len <= max { Ok(()) } else { Err(CollectionError::TooManyItems{ max, len }) }
+    })
+}
+
+fn first_duplicate<'a, T, K, F>(items: &'a [T], key: F) -> Result<(), CollectionError>
+where
+    K: Eq + Hash,
+    F: Fn(&'a T) -> K,
+{
+    let mut seen = HashMap::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        match seen.entry(key(item)) {
+            Entry::Occupied(first) => {
+                return Err(CollectionError::Duplicate{ first: *first.get(), index });
+            }
+            Entry::Vacant(slot) => {
+                slot.insert(index);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fails if two items are equal, reporting the first repeated one.
+pub fn unique<V, T, S>()
+    -> Rule<V, impl Fn(&V) -> Result<(), CollectionError> + Clone, S>
+where
+    V: AsRef<[T]>,
+    T: Eq + Hash,
+{
+    Rule::new(|v: &V| first_duplicate(v.as_ref(), |item: &T| item))
+}
+
+/// Fails if two items have the same `key`, e.g. `unique_by(|u: &User| u.id)`.
+pub fn unique_by<V, T, K, F, S>(key: F)
+    -> Rule<V, impl Fn(&V) -> Result<(), CollectionError> + Clone, S>
+where
+    V: AsRef<[T]>,
+    K: Eq + Hash,
+    F: Fn(&T) -> K + Clone,
+{
+    Rule::new(move |v: &V| first_duplicate(v.as_ref(), &key))
+}
+
+/// Fails unless the items are in non-decreasing order.
+pub fn sorted<V, T, S>()
+    -> Rule<V, impl Fn(&V) -> Result<(), CollectionError> + Clone, S>
+where
+    V: AsRef<[T]>,
+    T: PartialOrd,
+{
+    Rule::new(|v: &V| {
+        match v.as_ref().windows(2).position(|w| w[1] < w[0]) {
+            Some(i) => Err(CollectionError::NotSorted{ index: i + 1 }),
+            None => Ok(()),
+        }
+    })
+}
+
+/// Fails unless `item` is one of the items.
+pub fn contains<V, T, S>(item: T)
+    -> Rule<V, impl Fn(&V) -> Result<(), CollectionError> + Clone, S>
+where
+    V: AsRef<[T]>,
+    T: PartialEq + Clone,
+{
+    Rule::new(move |v: &V| {
+        if v.as_ref().contains(&item) { Ok(()) } else { Err(CollectionError::MissingItem) }
+    })
+}
+
+/// Fails if one of `keys` is missing from the map, reporting all missing keys.
+///
+/// ```
+/// # use error_combinator::check::{rules::collection::{required_keys, KeysError}, Check, CheckState};
+/// # use std::collections::HashMap;
+/// let checker = required_keys(["host", "port"].map(String::from));
+/// let config = HashMap::from([("host".to_string(), "localhost".to_string())]);
+/// let r = checker.check(CheckState::<_, ()>::new(config));
+/// assert_eq!(r.err(), Some(KeysError::Missing(vec!["port".to_string()])));
+/// ```
+pub fn required_keys<V, S>(keys: impl IntoIterator<Item = V::Key>)
+    -> Rule<V, impl Fn(&V) -> KeysResult<V::Key> + Clone, S>
+where
+    V: Keys,
+    V::Key: Clone,
+{
+    let keys: Vec<V::Key> = keys.into_iter().collect();
+    Rule::new(move |v: &V| {
+        let missing: Vec<V::Key> = keys.iter().filter(|k| !v.has_key(k)).cloned().collect();
+        if missing.is_empty() { Ok(()) } else { Err(KeysError::Missing(missing)) }
+    })
+}
+
+/// Fails if the map has a key outside of `allowed`, reporting all extra keys.
+pub fn no_extra_keys<V, S>(allowed: impl IntoIterator<Item = V::Key>)
+    -> Rule<V, impl Fn(&V) -> KeysResult<V::Key> + Clone, S>
+where
+    V: Keys,
+    V::Key: PartialEq + Clone,
+{
+    let allowed: Vec<V::Key> = allowed.into_iter().collect();
+    Rule::new(move |v: &V| {
+        let extra: Vec<V::Key> = v.key_iter().filter(|k| !allowed.contains(k)).cloned().collect();
+        if extra.is_empty() { Ok(()) } else { Err(KeysError::Extra(extra)) }
+    })
+}
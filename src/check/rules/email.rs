@@ -0,0 +1,249 @@
+//! Email address syntax rule, following RFC 5322 and RFC 6531 or the
+//! stricter HTML5 definition.
+//!
+//! Only the `local@domain` form (`addr-spec`) is accepted: display names,
+//! comments and obsolete syntax are not. Nothing is resolved. The rule
+//! reports an [`EmailError`] that names the failing component.
+//!
+//! ```
+//! use error_combinator::check::{
+//!     rules::email::{email, EmailOptions},
+//!     Check, CheckState,
+//! };
+//!
+//! let checker = email(EmailOptions::new().idn(true));
+//! assert!(checker.check(CheckState::<_, ()>::new("jörg@bücher.de")).is_passed());
+//! ```
+
+use std::fmt;
+
+use super::{
+    Rule,
+    net::{
+        LabelError,
+        NetError,
+        hostname_syntax,
+        idn_to_ascii,
+        ipv4_syntax,
+        ipv6_syntax,
+        label_syntax
+    }
+};
+
+/// Printable ASCII chars allowed in an unquoted local part besides letters
+/// and digits (`atext`).
+const ATEXT: &str = "!#$%&'*+-/=?^_`{|}~";
+
+/// Error of the [`email`] rule.
+///
+/// Offsets are byte offsets into the checked string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailError {
+    Empty,
+    /// No `@` between the local part and the domain.
+    MissingAt,
+    EmptyLocalPart,
+    /// The local part is longer than 64 bytes.
+    LocalPartTooLong(usize),
+    /// A char that is not allowed in the local part.
+    LocalPartChar{
+        offset: usize,
+        c: char
+    },
+    /// A leading, trailing or repeated dot in an unquoted local part.
+    LocalPartDot{
+        offset: usize
+    },
+    /// A quoted local part without its closing quote.
+    UnterminatedQuote,
+    Domain(NetError),
+    /// An invalid address literal, e.g. `[192.0.2.1]` or `[IPv6:2001:db8::1]`.
+    DomainLiteral(NetError),
+    /// Longer than 254 bytes, with the domain in its ASCII form.
+    TooLong(usize),
+}
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmailError::Empty => write!(f, "must not be empty"),
+            EmailError::MissingAt => write!(f, "email address must contain `@`"),
+            EmailError::EmptyLocalPart => write!(f, "email local part must not be empty"),
+            EmailError::LocalPartTooLong(n) => {
+                write!(f, "email local part must be at most 64 bytes, got {}", n)
+            }
+            EmailError::LocalPartChar{offset, c} => {
+                write!(f, "email local part contains {:?} at offset {}", c, offset)
+            }
+            EmailError::LocalPartDot{offset} => {
+                write!(f, "email local part has a misplaced dot at offset {}", offset)
+            }
+            EmailError::UnterminatedQuote => write!(f, "email local part is missing a closing quote"),
+            EmailError::Domain(e) => write!(f, "email domain: {}", e),
+            EmailError::DomainLiteral(e) => write!(f, "email address literal: {}", e),
+            EmailError::TooLong(n) => write!(f, "email address must be at most 254 bytes, got {}", n),
+        }
+    }
+}
+
+impl std::error::Error for EmailError {}
+
+/// Options of the [`email`] rule.
+#[derive(Debug, Clone, Default)]
+pub struct EmailOptions {
+    idn: bool,
+    html5: bool,
+}
+
+impl EmailOptions {
+    /// RFC 5322 syntax with ASCII addresses only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Syntax of `<input type="email">`: an unquoted local part of `atext`
+    /// chars and dots, and a domain of LDH labels. Quoted local parts and
+    /// address literals are rejected, while single-label domains such as
+    /// `localhost` are accepted.
+    pub fn html5() -> Self {
+        EmailOptions { html5: true, ..Self::default() }
+    }
+
+    /// Accepts internationalised addresses (RFC 6531): UTF-8 in the local
+    /// part, and domains checked in their punycode form. In HTML5 mode only
+    /// the domain may be internationalised. Domains are lowercased before
+    /// encoding, without IDNA normalisation or mapping.
+    pub fn idn(mut self, idn: bool) -> Self {
+        self.idn = idn;
+        self
+    }
+}
+
+fn is_atext(c: char, utf8: bool) -> bool {
+    c.is_ascii_alphanumeric()
+        || ATEXT.contains(c)
+        || (utf8 && !c.is_ascii() && !c.is_whitespace() && !c.is_control())
+}
+
+fn dot_atom(local: &str, utf8: bool) -> Result<(), EmailError> {
+    let mut prev = '.';
+    for (offset, c) in local.char_indices() {
+        if c == '.' {
+            if prev == '.' || offset + 1 == local.len() {
+                return Err(EmailError::LocalPartDot{ offset });
+            }
+        } else if !is_atext(c, utf8) {
+            return Err(EmailError::LocalPartChar{ offset, c });
+        }
+        prev = c;
+    }
+    Ok(())
+}
+
+/// `"..."` with printable ASCII, spaces and `\`-escapes inside.
+fn quoted_string(local: &str, utf8: bool) -> Result<(), EmailError> {
+    let mut escaped = false;
+    for (offset, c) in local.char_indices().skip(1) {
+        let printable = (' '..='~').contains(&c) || (utf8 && !c.is_ascii() && !c.is_control());
+        if !printable {
+            return Err(EmailError::LocalPartChar{ offset, c });
+        }
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            return match local[offset + 1..].chars().next() {
+                None => Ok(()),
+                Some(c) => Err(EmailError::LocalPartChar{ offset: offset + 1, c }),
+            };
+        }
+    }
+    Err(EmailError::UnterminatedQuote)
+}
+
+fn address_literal(domain: &str) -> Result<(), EmailError> {
+    let inner = domain.strip_prefix('[')
+        .and_then(|d| d.strip_suffix(']'))
+        .ok_or(EmailError::DomainLiteral(NetError::NotIpAddress))?;
+    match inner.get(..5) {
+        Some(tag) if tag.eq_ignore_ascii_case("IPv6:") => ipv6_syntax(&inner[5..], false),
+        _ => ipv4_syntax(inner),
+    }
+    .map_err(EmailError::DomainLiteral)
+}
+
+/// Domain of `<input type="email">`: LDH labels, with no limit on the
+/// number of labels and no rule on the last one.
+fn html5_domain(domain: &str) -> Result<(), NetError> {
+    if domain.is_empty() {
+        return Err(NetError::Empty);
+    }
+    domain.split('.').enumerate().try_for_each(|(index, label)| label_syntax(label, index))
+}
+
+fn email_syntax(s: &str, options: &EmailOptions) -> Result<(), EmailError> {
+    if s.is_empty() {
+        return Err(EmailError::Empty);
+    }
+    let at = s.rfind('@').ok_or(EmailError::MissingAt)?;
+    let (local, domain) = (&s[..at], &s[at + 1..]);
+    if local.is_empty() {
+        return Err(EmailError::EmptyLocalPart);
+    }
+
+    if options.html5 {
+        if let Some((offset, c)) = local.char_indices().find(|&(_, c)| !(c == '.' || is_atext(c, false))) {
+            return Err(EmailError::LocalPartChar{ offset, c });
+        }
+        let domain = if options.idn {
+            idn_to_ascii(domain).map_err(EmailError::Domain)?
+        } else {
+            domain.to_string()
+        };
+        return html5_domain(&domain).map_err(EmailError::Domain);
+    }
+
+    if 64 < local.len() {
+        return Err(EmailError::LocalPartTooLong(local.len()));
+    }
+    if local.starts_with('"') {
+        quoted_string(local, options.idn)?;
+    } else {
+        dot_atom(local, options.idn)?;
+    }
+
+    let domain = if domain.starts_with('[') {
+        address_literal(domain)?;
+        domain.to_string()
+    } else {
+        let ascii = if options.idn {
+            idn_to_ascii(domain).map_err(EmailError::Domain)?
+        } else {
+            domain.to_string()
+        };
+        if ascii.ends_with('.') {
+            let index = ascii.split('.').count() - 1;
+            return Err(EmailError::Domain(NetError::Label{ index, kind: LabelError::Empty }));
+        }
+        hostname_syntax(&ascii).map_err(EmailError::Domain)?;
+        ascii
+    };
+
+    let len = local.len() + 1 + domain.len();
+    if 254 < len {
+        return Err(EmailError::TooLong(len));
+    }
+    Ok(())
+}
+
+/// Email address such as `john.doe@example.com` or `"john doe"@[192.0.2.1]`.
+///
+/// The domain must be a DNS host name (see [`super::net::hostname`]) or an
+/// address literal. The local part is limited to 64 bytes and the whole
+/// address to 254, as SMTP requires (RFC 5321).
+pub fn email<T: AsRef<str>, S>(options: EmailOptions)
+    -> Rule<T, impl Fn(&T) -> Result<(), EmailError> + Clone, S>
+{
+    Rule::new(move |v: &T| email_syntax(v.as_ref(), &options))
+}
@@ -0,0 +1,527 @@
+//! Syntax rules for network identifiers: IP addresses, CIDR blocks,
+//! hostnames, ports and MAC addresses.
+//!
+//! The rules work on any `AsRef<str>` and only look at the syntax; nothing
+//! is resolved. They report a [`NetError`] that names the failing part,
+//! e.g. which octet or which label.
+//!
+//! ```
+//! use error_combinator::{
+//!     check::{
+//!         rules::net::{hostname, ip, NetError},
+//!         Check, CheckState,
+//!     },
+//!     cmberr::VecCombine,
+//! };
+//!
+//! // a host given by name or by address
+//! let host = hostname().either::<_, VecCombine<NetError>>(ip());
+//! assert!(host.check(CheckState::<_, ()>::new("192.0.2.1")).is_passed());
+//! ```
+
+use std::{
+    fmt,
+    ops::RangeInclusive
+};
+
+use super::{Rule, punycode};
+
+/// Why an IPv4 octet was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OctetError {
+    Empty,
+    NotDecimal,
+    /// `01`, which some parsers read as octal.
+    LeadingZero,
+    TooLarge,
+}
+
+/// Why an IPv6 group was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupError {
+    Empty,
+    NotHex,
+    /// More than four hex digits.
+    TooLong,
+}
+
+/// Why a hostname label was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelError {
+    Empty,
+    /// Longer than 63 bytes.
+    TooLong,
+    InvalidChar(char),
+    LeadingHyphen,
+    TrailingHyphen,
+    /// The last label is all digits, which would make the name look like an
+    /// IPv4 address (RFC 1123, section 2.1).
+    NumericTld,
+    /// An `xn--` label that does not decode, or a label that cannot be encoded.
+    Punycode,
+}
+
+/// Error of a network identifier rule.
+///
+/// Indices are zero-based and count octets, groups or labels from the left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetError {
+    Empty,
+    /// An IPv4 address needs exactly 4 octets; `count` were found.
+    Ipv4OctetCount(usize),
+    Ipv4Octet{
+        index: usize,
+        kind: OctetError
+    },
+    /// An IPv6 address needs 8 groups, or fewer with `::`; `count` were found.
+    Ipv6GroupCount(usize),
+    Ipv6Group{
+        index: usize,
+        kind: GroupError
+    },
+    /// `::` appears more than once.
+    Ipv6DoubleColon,
+    /// An embedded IPv4 address that is invalid or not at the end.
+    Ipv6EmbeddedIpv4(Box<NetError>),
+    /// The zone ID after `%` is empty or has a char outside of RFC 6874
+    /// unreserved chars.
+    Ipv6ZoneId,
+    /// Neither an IPv4 nor an IPv6 address.
+    NotIpAddress,
+    /// No `/prefix` after the address.
+    CidrMissingPrefix,
+    /// The prefix is not a number, or is above the maximum of the address family.
+    CidrPrefix{
+        max: u8
+    },
+    /// The prefix is outside of the allowed range.
+    CidrPrefixRange{
+        prefix: u8,
+        min: u8,
+        max: u8
+    },
+    /// Longer than 253 bytes, not counting a trailing dot.
+    HostnameTooLong(usize),
+    Label{
+        index: usize,
+        kind: LabelError
+    },
+    PortNotNumber,
+    PortRange{
+        port: u32,
+        min: u16,
+        max: u16
+    },
+    /// The number does not even fit in a `u32`, so it is not reported.
+    PortOverflow{
+        min: u16,
+        max: u16
+    },
+    /// A port range is not written `low-high`, or `low` is above `high`.
+    PortRangeFormat,
+    /// Not one of `xx:xx:xx:xx:xx:xx`, `xx-xx-xx-xx-xx-xx` or `xxxx.xxxx.xxxx`.
+    MacFormat,
+    MacGroup{
+        index: usize
+    },
+}
+
+impl fmt::Display for OctetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OctetError::Empty => write!(f, "is empty"),
+            OctetError::NotDecimal => write!(f, "is not a decimal number"),
+            OctetError::LeadingZero => write!(f, "has a leading zero"),
+            OctetError::TooLarge => write!(f, "is greater than 255"),
+        }
+    }
+}
+
+impl fmt::Display for GroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupError::Empty => write!(f, "is empty"),
+            GroupError::NotHex => write!(f, "is not hexadecimal"),
+            GroupError::TooLong => write!(f, "has more than 4 digits"),
+        }
+    }
+}
+
+impl fmt::Display for LabelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LabelError::Empty => write!(f, "is empty"),
+            LabelError::TooLong => write!(f, "is longer than 63 bytes"),
+            LabelError::InvalidChar(c) => write!(f, "contains {:?}", c),
+            LabelError::LeadingHyphen => write!(f, "starts with a hyphen"),
+            LabelError::TrailingHyphen => write!(f, "ends with a hyphen"),
+            LabelError::NumericTld => write!(f, "is all digits"),
+            LabelError::Punycode => write!(f, "is not valid punycode"),
+        }
+    }
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetError::Empty => write!(f, "must not be empty"),
+            NetError::Ipv4OctetCount(n) => write!(f, "IPv4 address must have 4 octets, got {}", n),
+            NetError::Ipv4Octet{index, kind} => write!(f, "IPv4 octet {} {}", index + 1, kind),
+            NetError::Ipv6GroupCount(n) => write!(f, "IPv6 address must have 8 groups, got {}", n),
+            NetError::Ipv6Group{index, kind} => write!(f, "IPv6 group {} {}", index + 1, kind),
+            NetError::Ipv6DoubleColon => write!(f, "IPv6 address must contain `::` at most once"),
+            NetError::Ipv6EmbeddedIpv4(e) => write!(f, "embedded IPv4 address: {}", e),
+            NetError::Ipv6ZoneId => write!(f, "IPv6 zone ID is invalid"),
+            NetError::NotIpAddress => write!(f, "must be an IPv4 or IPv6 address"),
+            NetError::CidrMissingPrefix => write!(f, "CIDR block must have a `/prefix`"),
+            NetError::CidrPrefix{max} => write!(f, "CIDR prefix must be a number up to {}", max),
+            NetError::CidrPrefixRange{prefix, min, max} => {
+                write!(f, "CIDR prefix must be between {} and {}, got {}", min, max, prefix)
+            }
+            NetError::HostnameTooLong(n) => write!(f, "hostname must be at most 253 bytes, got {}", n),
+            NetError::Label{index, kind} => write!(f, "label {} {}", index + 1, kind),
+            NetError::PortNotNumber => write!(f, "port must be a number"),
+            NetError::PortRange{port, min, max} => {
+                write!(f, "port must be between {} and {}, got {}", min, max, port)
+            }
+            NetError::PortOverflow{min, max} => write!(f, "port must be between {} and {}", min, max),
+            NetError::PortRangeFormat => write!(f, "port range must be written `low-high`"),
+            NetError::MacFormat => write!(f, "MAC address must be 6 hex bytes"),
+            NetError::MacGroup{index} => write!(f, "MAC address group {} is not hexadecimal", index + 1),
+        }
+    }
+}
+
+impl std::error::Error for NetError {}
+
+// ======================= syntax checks =======================
+
+pub(crate) fn ipv4_syntax(s: &str) -> Result<(), NetError> {
+    if s.is_empty() {
+        return Err(NetError::Empty);
+    }
+    let count = s.split('.').count();
+    if count != 4 {
+        return Err(NetError::Ipv4OctetCount(count));
+    }
+    for (index, octet) in s.split('.').enumerate() {
+        let kind = if octet.is_empty() {
+            OctetError::Empty
+        } else if !octet.bytes().all(|b| b.is_ascii_digit()) {
+            OctetError::NotDecimal
+        } else if octet.len() > 1 && octet.starts_with('0') {
+            OctetError::LeadingZero
+        } else if octet.len() > 3 || octet.parse::<u16>().map_or(true, |n| n > 255) {
+            OctetError::TooLarge
+        } else {
+            continue;
+        };
+        return Err(NetError::Ipv4Octet{ index, kind });
+    }
+    Ok(())
+}
+
+fn is_zone_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~')
+}
+
+fn ipv6_groups(part: &str, first_index: usize, last: bool) -> Result<usize, NetError> {
+    if part.is_empty() {
+        return Ok(0);
+    }
+    let groups: Vec<&str> = part.split(':').collect();
+    let mut count = 0;
+    for (i, group) in groups.iter().enumerate() {
+        let index = first_index + count;
+        if group.contains('.') {
+            if !(last && i + 1 == groups.len()) {
+                return Err(NetError::Ipv6EmbeddedIpv4(Box::new(NetError::NotIpAddress)));
+            }
+            ipv4_syntax(group).map_err(|e| NetError::Ipv6EmbeddedIpv4(Box::new(e)))?;
+            count += 2;
+            continue;
+        }
+        let kind = if group.is_empty() {
+            GroupError::Empty
+        } else if !group.bytes().all(|b| b.is_ascii_hexdigit()) {
+            GroupError::NotHex
+        } else if group.len() > 4 {
+            GroupError::TooLong
+        } else {
+            count += 1;
+            continue;
+        };
+        return Err(NetError::Ipv6Group{ index, kind });
+    }
+    Ok(count)
+}
+
+/// IPv6 address without brackets, with an optional `%zone` suffix when
+/// `zone` is `true`.
+pub(crate) fn ipv6_syntax(s: &str, zone: bool) -> Result<(), NetError> {
+    if s.is_empty() {
+        return Err(NetError::Empty);
+    }
+    let addr = match s.split_once('%') {
+        Some((addr, id)) if zone => {
+            if id.is_empty() || !id.chars().all(is_zone_char) {
+                return Err(NetError::Ipv6ZoneId);
+            }
+            addr
+        }
+        Some(_) => return Err(NetError::Ipv6ZoneId),
+        None => s,
+    };
+
+    match addr.split_once("::") {
+        Some((head, tail)) => {
+            if tail.contains("::") || tail.starts_with(':') {
+                return Err(NetError::Ipv6DoubleColon);
+            }
+            let head_count = ipv6_groups(head, 0, false)?;
+            let tail_count = ipv6_groups(tail, head_count, true)?;
+            if 7 < head_count + tail_count {
+                return Err(NetError::Ipv6GroupCount(head_count + tail_count));
+            }
+        }
+        None => {
+            let count = ipv6_groups(addr, 0, true)?;
+            if count != 8 {
+                return Err(NetError::Ipv6GroupCount(count));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn label_syntax(label: &str, index: usize) -> Result<(), NetError> {
+    let kind = if label.is_empty() {
+        LabelError::Empty
+    } else if 63 < label.len() {
+        LabelError::TooLong
+    } else if let Some(c) = label.chars().find(|c| !(c.is_ascii_alphanumeric() || *c == '-')) {
+        LabelError::InvalidChar(c)
+    } else if label.starts_with('-') {
+        LabelError::LeadingHyphen
+    } else if label.ends_with('-') {
+        LabelError::TrailingHyphen
+    } else {
+        return Ok(());
+    };
+    Err(NetError::Label{ index, kind })
+}
+
+pub(crate) fn hostname_syntax(s: &str) -> Result<(), NetError> {
+    if s.is_empty() {
+        return Err(NetError::Empty);
+    }
+    let name = s.strip_suffix('.').unwrap_or(s);
+    if 253 < name.len() {
+        return Err(NetError::HostnameTooLong(name.len()));
+    }
+    let mut last = (0, "");
+    for (index, label) in name.split('.').enumerate() {
+        label_syntax(label, index)?;
+        last = (index, label);
+    }
+    if last.1.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(NetError::Label{ index: last.0, kind: LabelError::NumericTld });
+    }
+    Ok(())
+}
+
+/// Converts an internationalised domain name to its ASCII form by
+/// lowercasing non-ASCII labels and encoding them as `xn--` punycode.
+///
+/// This is not full IDNA: there is no NFC normalisation and no UTS #46
+/// mapping or validity check, so e.g. a decomposed `u` + U+0308 gives a
+/// different label than a precomposed `ü`.
+///
+/// Only the labels are checked here; run [`hostname_syntax`] on the result
+/// for the limits on the whole name. Existing `xn--` labels must decode.
+pub(crate) fn idn_to_ascii(s: &str) -> Result<String, NetError> {
+    let mut labels = Vec::new();
+    for (index, label) in s.split(['.', '\u{3002}', '\u{ff0e}', '\u{ff61}']).enumerate() {
+        if label.is_ascii() {
+            let ace = label.get(..4).is_some_and(|p| p.eq_ignore_ascii_case("xn--"));
+            if ace && !punycode::decode(&label[4..]).is_some_and(|u| {
+                !u.is_ascii() && punycode::encode(&u).is_some_and(|e| e.eq_ignore_ascii_case(&label[4..]))
+            }) {
+                return Err(NetError::Label{ index, kind: LabelError::Punycode });
+            }
+            labels.push(label.to_string());
+            continue;
+        }
+        let label = label.to_lowercase();
+        let bad = |c: &char| {
+            if c.is_ascii() {
+                !(c.is_ascii_alphanumeric() || *c == '-')
+            } else {
+                c.is_whitespace() || c.is_control()
+            }
+        };
+        if let Some(c) = label.chars().find(bad) {
+            return Err(NetError::Label{ index, kind: LabelError::InvalidChar(c) });
+        }
+        match punycode::encode(&label) {
+            Some(encoded) => labels.push(format!("xn--{}", encoded)),
+            None => return Err(NetError::Label{ index, kind: LabelError::Punycode }),
+        }
+    }
+    Ok(labels.join("."))
+}
+
+pub(crate) fn port_number(s: &str, bounds: &RangeInclusive<u16>) -> Result<u16, NetError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(NetError::PortNotNumber);
+    }
+    let (min, max) = (*bounds.start(), *bounds.end());
+    let port = s.parse::<u32>().map_err(|_| NetError::PortOverflow{ min, max })?;
+    if port < u32::from(min) || u32::from(max) < port {
+        return Err(NetError::PortRange{ port, min, max });
+    }
+    Ok(port as u16)
+}
+
+fn mac_syntax(s: &str) -> Result<(), NetError> {
+    let groups: Vec<&str> = if s.contains(':') {
+        s.split(':').collect()
+    } else if s.contains('-') {
+        s.split('-').collect()
+    } else {
+        s.split('.').collect()
+    };
+    // six groups use `:` or `-`, three use `.`
+    let width = match groups.len() {
+        6 if s.contains([':', '-']) => 2,
+        3 if !s.contains([':', '-']) => 4,
+        _ => return Err(NetError::MacFormat),
+    };
+    for (index, group) in groups.iter().enumerate() {
+        if group.len() != width {
+            return Err(NetError::MacFormat);
+        }
+        if !group.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(NetError::MacGroup{ index });
+        }
+    }
+    Ok(())
+}
+
+// ======================= rules =======================
+
+fn rule<T: AsRef<str>, S>(f: impl Fn(&str) -> Result<(), NetError> + Clone)
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    Rule::new(move |v: &T| f(v.as_ref()))
+}
+
+/// Dotted-decimal IPv4 address, e.g. `192.168.0.1`. Leading zeros are rejected.
+pub fn ipv4<T: AsRef<str>, S>()
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(ipv4_syntax)
+}
+
+/// IPv6 address (RFC 4291), e.g. `fe80::1`, `::ffff:192.0.2.1` or
+/// `fe80::1%eth0` with a zone ID (RFC 6874).
+pub fn ipv6<T: AsRef<str>, S>()
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(|s: &str| ipv6_syntax(s, true))
+}
+
+/// IPv4 or IPv6 address.
+pub fn ip<T: AsRef<str>, S>()
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(|s: &str| {
+        if s.contains(':') {
+            ipv6_syntax(s, true)
+        } else if s.contains('.') {
+            ipv4_syntax(s)
+        } else if s.is_empty() {
+            Err(NetError::Empty)
+        } else {
+            Err(NetError::NotIpAddress)
+        }
+    })
+}
+
+/// CIDR block such as `10.0.0.0/8` or `2001:db8::/32`, whose prefix length
+/// must be in `prefix` (and at most 32 or 128 for the address family).
+/// Leading zeros in the prefix length, as in `/08`, are rejected.
+pub fn cidr<T: AsRef<str>, S>(prefix: RangeInclusive<u8>)
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(move |s: &str| {
+        let (addr, len) = s.split_once('/').ok_or(NetError::CidrMissingPrefix)?;
+        let max = if addr.contains(':') {
+            ipv6_syntax(addr, false)?;
+            128
+        } else {
+            ipv4_syntax(addr)?;
+            32
+        };
+        let valid = !len.is_empty() && len.len() <= 3 && len.bytes().all(|b| b.is_ascii_digit())
+            && (len == "0" || !len.starts_with('0'));
+        let len = match len.parse::<u8>() {
+            Ok(n) if valid && n <= max => n,
+            _ => return Err(NetError::CidrPrefix{ max }),
+        };
+        if !prefix.contains(&len) {
+            return Err(NetError::CidrPrefixRange{ prefix: len, min: *prefix.start(), max: *prefix.end() });
+        }
+        Ok(())
+    })
+}
+
+/// Hostname per RFC 1123: dot-separated labels of ASCII letters, digits and
+/// hyphens, 63 bytes per label and 253 in total. A trailing dot is allowed.
+///
+/// An all-digit last label is rejected so that IPv4 addresses are not
+/// accepted as hostnames. Internationalised names must be punycode-encoded.
+pub fn hostname<T: AsRef<str>, S>()
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(hostname_syntax)
+}
+
+/// Single domain label, e.g. a subdomain chosen by a user.
+pub fn domain_label<T: AsRef<str>, S>()
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(|s: &str| label_syntax(s, 0))
+}
+
+/// Decimal port number in `bounds`, e.g. `port(1024..=65535)`.
+pub fn port<T: AsRef<str>, S>(bounds: RangeInclusive<u16>)
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(move |s: &str| port_number(s, &bounds).map(|_| ()))
+}
+
+/// Port range `low-high` with both ends in `bounds` and `low <= high`.
+/// A single port is accepted as well.
+pub fn port_range<T: AsRef<str>, S>(bounds: RangeInclusive<u16>)
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(move |s: &str| {
+        match s.split_once('-') {
+            Some((lo, hi)) => {
+                let lo = port_number(lo, &bounds)?;
+                let hi = port_number(hi, &bounds)?;
+                if lo <= hi { Ok(()) } else { Err(NetError::PortRangeFormat) }
+            }
+            None => port_number(s, &bounds).map(|_| ()),
+        }
+    })
+}
+
+/// MAC-48 address written `00:1a:2b:3c:4d:5e`, `00-1A-2B-3C-4D-5E` or
+/// `001a.2b3c.4d5e`.
+pub fn mac<T: AsRef<str>, S>()
+    -> Rule<T, impl Fn(&T) -> Result<(), NetError> + Clone, S>
+{
+    rule(mac_syntax)
+}
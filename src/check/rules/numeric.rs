@@ -0,0 +1,288 @@
+//! Rules on integer and float values.
+//!
+//! The rules report a [`NumericError`] carrying the violated bound, whose
+//! `Display` reads like "must be between 1 and 65535".
+//!
+//! ```
+//! use error_combinator::check::{
+//!     rules::numeric::{between, range},
+//!     Check, CheckState,
+//! };
+//!
+//! let port = between::<1, 65535, u32, ()>();
+//! let err = port.check(CheckState::new(0)).err().unwrap();
+//! assert_eq!(err.to_string(), "must be between 1 and 65535");
+//!
+//! let ratio = range(0.0..=1.0);
+//! assert!(ratio.check(CheckState::<_, ()>::new(0.5)).is_passed());
+//! ```
+
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds}
+};
+
+use super::Rule;
+
+/// Integer and float types the numeric rules work on.
+pub trait Number: Copy + PartialOrd + fmt::Display {
+    const ZERO: Self;
+
+    /// `true` if `self` is a multiple of `divisor`. For floats the quotient
+    /// only has to be within a few ulps of an integer, see [`multiple_of`].
+    fn is_multiple_of(self, divisor: Self) -> bool;
+
+    fn is_finite(self) -> bool;
+}
+
+/// Float types, for [`finite`].
+pub trait Float: Number {}
+
+macro_rules! impl_number_int {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+
+                fn is_multiple_of(self, divisor: Self) -> bool {
+                    divisor != 0 && self.wrapping_rem(divisor) == 0
+                }
+
+                fn is_finite(self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_number_float {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0.0;
+
+                fn is_multiple_of(self, divisor: Self) -> bool {
+                    // `0.3 % 0.1` is not zero, so compare the quotient to the
+                    // nearest integer instead, relative to its magnitude
+                    let q = self / divisor;
+                    divisor != 0.0
+                        && (q - q.round()).abs() <= q.abs().max(1.0) * 4.0 * <$t>::EPSILON
+                }
+
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+            }
+
+            impl Float for $t {}
+        )*
+    };
+}
+
+impl_number_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_number_float!(f32, f64);
+
+/// Error of a numeric rule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericError<N> {
+    /// The value is outside of `min`..`max`.
+    OutOfRange{
+        min: Bound<N>,
+        max: Bound<N>
+    },
+    NotPositive,
+    Negative,
+    NotMultipleOf{
+        divisor: N
+    },
+    NotFinite,
+    /// The value has more than `scale` digits after the decimal point, or
+    /// more than `precision - scale` before it.
+    TooManyDigits{
+        precision: u32,
+        scale: u32
+    },
+    /// No value of the checked type is in the range `min..=max` of [`between`].
+    EmptyRange{
+        min: i128,
+        max: i128
+    },
+}
+
+impl<N: fmt::Display> fmt::Display for NumericError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumericError::OutOfRange{min, max} => match (min, max) {
+                (Bound::Included(lo), Bound::Included(hi)) => {
+                    write!(f, "must be between {} and {}", lo, hi)
+                }
+                (Bound::Included(lo), Bound::Excluded(hi)) => {
+                    write!(f, "must be at least {} and less than {}", lo, hi)
+                }
+                (Bound::Excluded(lo), Bound::Included(hi)) => {
+                    write!(f, "must be greater than {} and at most {}", lo, hi)
+                }
+                (Bound::Excluded(lo), Bound::Excluded(hi)) => {
+                    write!(f, "must be greater than {} and less than {}", lo, hi)
+                }
+                (Bound::Included(lo), Bound::Unbounded) => write!(f, "must be at least {}", lo),
+                (Bound::Excluded(lo), Bound::Unbounded) => write!(f, "must be greater than {}", lo),
+                (Bound::Unbounded, Bound::Included(hi)) => write!(f, "must be at most {}", hi),
+                (Bound::Unbounded, Bound::Excluded(hi)) => write!(f, "must be less than {}", hi),
+                (Bound::Unbounded, Bound::Unbounded) => write!(f, "must be in range"),
+            },
+            NumericError::NotPositive => write!(f, "must be positive"),
+            NumericError::Negative => write!(f, "must not be negative"),
+            NumericError::NotMultipleOf{divisor} => write!(f, "must be a multiple of {}", divisor),
+            NumericError::NotFinite => write!(f, "must be a finite number"),
+            NumericError::TooManyDigits{precision, scale} => write!(
+                f,
+                "must have at most {} digits, {} of them after the decimal point",
+                precision, scale
+            ),
+            NumericError::EmptyRange{min, max} => write!(f, "must be between {} and {}", min, max),
+        }
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> std::error::Error for NumericError<N> {}
+
+fn rule<N, S, F>(f: F) -> Rule<N, F, S>
+where
+    F: Fn(&N) -> Result<(), NumericError<N>>,
+{
+    Rule::new(f)
+}
+
+fn in_bounds<N: PartialOrd>(value: &N, min: &Bound<N>, max: &Bound<N>) -> bool {
+    let above = match min {
+        Bound::Included(lo) => lo <= value,
+        Bound::Excluded(lo) => lo < value,
+        Bound::Unbounded => true,
+    };
+    let below = match max {
+        Bound::Included(hi) => value <= hi,
+        Bound::Excluded(hi) => value < hi,
+        Bound::Unbounded => true,
+    };
+    above && below
+}
+
+/// Fails unless the value is in `bounds`, e.g. `range(1..=65535)` or `range(0.0..1.0)`.
+///
+/// A NaN is never in range.
+pub fn range<N: Number, S>(bounds: impl RangeBounds<N>)
+    -> Rule<N, impl Fn(&N) -> Result<(), NumericError<N>> + Clone, S>
+{
+    let min = bounds.start_bound().cloned();
+    let max = bounds.end_bound().cloned();
+    rule(move |v: &N| {
+        if in_bounds(v, &min, &max) {
+            Ok(())
+        } else {
+            Err(NumericError::OutOfRange{ min, max })
+        }
+    })
+}
+
+/// Integer range `LO..=HI` fixed at compile time.
+///
+/// A bound that `N` cannot represent is left open if it lies beyond the
+/// range of `N`: `between::<-5, 10, u8, _>()` only checks the upper bound.
+/// If the whole range lies beyond it, as in `between::<300, 400, u8, _>()`,
+/// every value fails with [`NumericError::EmptyRange`].
+pub fn between<const LO: i128, const HI: i128, N, S>()
+    -> Rule<N, impl Fn(&N) -> Result<(), NumericError<N>> + Clone, S>
+where
+    N: Number + TryFrom<i128>,
+{
+    const { assert!(LO <= HI, "`between` requires LO <= HI") };
+    // every integer type holds 0, so a bound that does not fit is above
+    // `N::MAX` if it is positive and below `N::MIN` if it is negative
+    let min = N::try_from(LO).map_or(Bound::Unbounded, Bound::Included);
+    let max = N::try_from(HI).map_or(Bound::Unbounded, Bound::Included);
+    let empty = (matches!(min, Bound::Unbounded) && 0 < LO)
+        || (matches!(max, Bound::Unbounded) && HI < 0);
+    rule(move |v: &N| {
+        if empty {
+            Err(NumericError::EmptyRange{ min: LO, max: HI })
+        } else if in_bounds(v, &min, &max) {
+            Ok(())
+        } else {
+            Err(NumericError::OutOfRange{ min, max })
+        }
+    })
+}
+
+/// Fails unless the value is greater than zero.
+pub fn positive<N: Number, S>()
+    -> Rule<N, impl Fn(&N) -> Result<(), NumericError<N>> + Clone, S>
+{
+    rule(|v: &N| if N::ZERO < *v { Ok(()) } else { Err(NumericError::NotPositive) })
+}
+
+/// Fails if the value is less than zero. A NaN fails as well.
+pub fn non_negative<N: Number, S>()
+    -> Rule<N, impl Fn(&N) -> Result<(), NumericError<N>> + Clone, S>
+{
+    rule(|v: &N| if N::ZERO <= *v { Ok(()) } else { Err(NumericError::Negative) })
+}
+
+/// Fails unless the value is a multiple of `divisor`. Nothing is a multiple of zero.
+///
+/// Floats are compared with a tolerance: the value passes when
+/// `value / divisor` is within `4 * EPSILON` (relative, at least absolute
+/// for quotients below 1) of an integer, so `multiple_of(0.1)` accepts `0.3`
+/// although `0.3 % 0.1` is not zero.
+pub fn multiple_of<N: Number, S>(divisor: N)
+    -> Rule<N, impl Fn(&N) -> Result<(), NumericError<N>> + Clone, S>
+{
+    rule(move |v: &N| {
+        if v.is_multiple_of(divisor) {
+            Ok(())
+        } else {
+            Err(NumericError::NotMultipleOf{ divisor })
+        }
+    })
+}
+
+/// Fails on NaN and infinities.
+pub fn finite<N: Float, S>()
+    -> Rule<N, impl Fn(&N) -> Result<(), NumericError<N>> + Clone, S>
+{
+    rule(|v: &N| if v.is_finite() { Ok(()) } else { Err(NumericError::NotFinite) })
+}
+
+/// Counts the digits before and after the decimal point of the shortest
+/// decimal representation of `value`, ignoring leading and trailing zeros.
+fn digits<N: fmt::Display>(value: &N) -> Option<(u32, u32)> {
+    let s = value.to_string();
+    let s = s.trim_start_matches(['-', '+']);
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    if !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let int = int.trim_start_matches('0');
+    let frac = frac.trim_end_matches('0');
+    Some((int.len() as u32, frac.len() as u32))
+}
+
+/// Fails unless the value fits a SQL-like `DECIMAL(precision, scale)`: at
+/// most `scale` digits after the decimal point and at most
+/// `precision - scale` before it.
+///
+/// Floats are measured on their shortest round-trip representation, so
+/// `0.1_f64` has one decimal digit. NaN and infinities fail.
+pub fn decimal<N: Number, S>(precision: u32, scale: u32)
+    -> Rule<N, impl Fn(&N) -> Result<(), NumericError<N>> + Clone, S>
+{
+    rule(move |v: &N| {
+        match digits(v) {
+            Some((int, frac)) if frac <= scale && int <= precision.saturating_sub(scale) => {
+                Ok(())
+            }
+            _ => Err(NumericError::TooManyDigits{ precision, scale }),
+        }
+    })
+}
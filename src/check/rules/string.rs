@@ -0,0 +1,226 @@
+//! Rules on string values: `&str`, `String` or any other `AsRef<str>`.
+//!
+//! ```
+//! use error_combinator::{
+//!     check::{
+//!         rules::string::{min_len, starts_with, Length},
+//!         Check, CheckState,
+//!     },
+//!     cmberr::VecCombine,
+//! };
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum ValidateErr {
+//!     StartsWithHello,
+//!     Min6,
+//! }
+//!
+//! let checker = starts_with("hello", ValidateErr::StartsWithHello)
+//!     .and::<_, VecCombine<ValidateErr>>(min_len(Length::Bytes(6), ValidateErr::Min6));
+//! let r = checker.check(CheckState::<_, ()>::new("hello"));
+//! assert_eq!(r.err(), Some(vec![ValidateErr::Min6]));
+//! ```
+
+use std::ops::RangeInclusive;
+
+use super::Rule;
+
+/// Length of a string in a given unit. A bare `usize` counts chars.
+///
+/// `Graphemes` only exists with the `graphemes` feature, so the enum is
+/// non-exhaustive: a `match` on it keeps compiling when some other crate in
+/// the build turns the feature on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Length {
+    /// UTF-8 bytes, as returned by `str::len`.
+    Bytes(usize),
+    /// Unicode scalar values.
+    Chars(usize),
+    /// Extended grapheme clusters, i.e. user-perceived characters.
+    #[cfg(feature = "graphemes")]
+    Graphemes(usize),
+}
+
+impl Length {
+    pub fn value(&self) -> usize {
+        match self {
+            Length::Bytes(n) | Length::Chars(n) => *n,
+            #[cfg(feature = "graphemes")]
+            Length::Graphemes(n) => *n,
+        }
+    }
+
+    /// Length of `s` in the unit of `self`.
+    pub fn measure(&self, s: &str) -> usize {
+        match self {
+            Length::Bytes(_) => s.len(),
+            Length::Chars(_) => s.chars().count(),
+            #[cfg(feature = "graphemes")]
+            Length::Graphemes(_) => {
+                unicode_segmentation::UnicodeSegmentation::graphemes(s, true).count()
+            }
+        }
+    }
+}
+
+impl From<usize> for Length {
+    fn from(n: usize) -> Self {
+        Length::Chars(n)
+    }
+}
+
+/// Set of chars accepted by [`matches_charset`].
+pub trait Charset {
+    fn contains_char(&self, c: char) -> bool;
+}
+
+impl Charset for str {
+    fn contains_char(&self, c: char) -> bool {
+        self.contains(c)
+    }
+}
+
+impl Charset for String {
+    fn contains_char(&self, c: char) -> bool {
+        self.contains(c)
+    }
+}
+
+impl Charset for [char] {
+    fn contains_char(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl<const N: usize> Charset for [char; N] {
+    fn contains_char(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl Charset for RangeInclusive<char> {
+    fn contains_char(&self, c: char) -> bool {
+        self.contains(&c)
+    }
+}
+
+impl<const N: usize> Charset for [RangeInclusive<char>; N] {
+    fn contains_char(&self, c: char) -> bool {
+        self.iter().any(|r| r.contains(&c))
+    }
+}
+
+impl<C: Charset + ?Sized> Charset for &C {
+    fn contains_char(&self, c: char) -> bool {
+        (**self).contains_char(c)
+    }
+}
+
+fn rule<T: AsRef<str>, S, E>(f: impl Fn(&str) -> Result<(), E> + Clone)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    Rule::new(move |v: &T| f(v.as_ref()))
+}
+
+fn ok_or<E: Clone>(ok: bool, err: &E) -> Result<(), E> {
+    if ok { Ok(()) } else { Err(err.clone()) }
+}
+
+/// Fails with `err` if the string is shorter than `n`.
+pub fn min_len<T: AsRef<str>, S, E: Clone>(n: impl Into<Length>, err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    let n = n.into();
+    rule(move |s: &str| ok_or(n.value() <= n.measure(s), &err))
+}
+
+/// Fails with `err` if the string is longer than `n`.
+pub fn max_len<T: AsRef<str>, S, E: Clone>(n: impl Into<Length>, err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    let n = n.into();
+    rule(move |s: &str| ok_or(n.measure(s) <= n.value(), &err))
+}
+
+/// Fails with `err` unless `min <= len <= max`, each bound measured in its own unit.
+pub fn len_between<T: AsRef<str>, S, E: Clone>(min: impl Into<Length>, max: impl Into<Length>, err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    let (min, max) = (min.into(), max.into());
+    rule(move |s: &str| {
+        ok_or(min.value() <= min.measure(s) && max.measure(s) <= max.value(), &err)
+    })
+}
+
+pub fn starts_with<T: AsRef<str>, S, E: Clone>(prefix: impl Into<String>, err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    let prefix = prefix.into();
+    rule(move |s: &str| ok_or(s.starts_with(prefix.as_str()), &err))
+}
+
+pub fn ends_with<T: AsRef<str>, S, E: Clone>(suffix: impl Into<String>, err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    let suffix = suffix.into();
+    rule(move |s: &str| ok_or(s.ends_with(suffix.as_str()), &err))
+}
+
+pub fn contains<T: AsRef<str>, S, E: Clone>(needle: impl Into<String>, err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    let needle = needle.into();
+    rule(move |s: &str| ok_or(s.contains(needle.as_str()), &err))
+}
+
+pub fn ascii_only<T: AsRef<str>, S, E: Clone>(err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    rule(move |s: &str| ok_or(s.is_ascii(), &err))
+}
+
+/// Fails with `err` unless every char is alphanumeric in the Unicode sense
+/// (`char::is_alphanumeric`). Combine with [`ascii_only`] for `[A-Za-z0-9]`.
+pub fn alphanumeric<T: AsRef<str>, S, E: Clone>(err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    rule(move |s: &str| ok_or(s.chars().all(char::is_alphanumeric), &err))
+}
+
+/// Fails with `err` if the string contains Unicode white space.
+pub fn no_whitespace<T: AsRef<str>, S, E: Clone>(err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    rule(move |s: &str| ok_or(!s.chars().any(char::is_whitespace), &err))
+}
+
+/// Fails with `err` if the string contains an uppercase char.
+pub fn lowercase<T: AsRef<str>, S, E: Clone>(err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    rule(move |s: &str| ok_or(!s.chars().any(char::is_uppercase), &err))
+}
+
+/// Fails with `err` if the string contains a lowercase char.
+pub fn uppercase<T: AsRef<str>, S, E: Clone>(err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    rule(move |s: &str| ok_or(!s.chars().any(char::is_lowercase), &err))
+}
+
+/// Fails with `err` unless every char belongs to `charset`.
+///
+/// ```
+/// # use error_combinator::check::{rules::string::matches_charset, Check, CheckState};
+/// let hex = matches_charset("0123456789abcdef", "not hex");
+/// assert!(hex.check(CheckState::<_, ()>::new("c0ffee")).is_passed());
+///
+/// let ident = matches_charset(['a'..='z', '0'..='9', '_'..='_'], "bad ident");
+/// assert!(ident.check(CheckState::<_, ()>::new("Snake_case")).is_failed());
+/// ```
+pub fn matches_charset<T: AsRef<str>, S, E: Clone, C: Charset + Clone>(charset: C, err: E)
+    -> Rule<T, impl Fn(&T) -> Result<(), E> + Clone, S>
+{
+    rule(move |s: &str| ok_or(s.chars().all(|c| charset.contains_char(c)), &err))
+}
@@ -0,0 +1,334 @@
+//! URL syntax rule, following RFC 3986 or the stricter HTML5 definition.
+//!
+//! Only absolute URLs are accepted: a relative reference like `/a/b` has no
+//! scheme. The rule checks the syntax and nothing is resolved. It reports a
+//! [`UrlError`] that names the failing component.
+//!
+//! ```
+//! use error_combinator::check::{
+//!     rules::url::{url, UrlError, UrlOptions},
+//!     Check, CheckState,
+//! };
+//!
+//! let homepage = url(UrlOptions::new().schemes(["http", "https"]).require_host(true).dns_host(true));
+//! let r = homepage.check(CheckState::<_, ()>::new("mailto:a@example.com"));
+//! assert_eq!(r.err(), Some(UrlError::SchemeNotAllowed("mailto".to_string())));
+//! ```
+
+use std::fmt;
+
+use super::{
+    Rule,
+    net::{
+        NetError,
+        hostname_syntax,
+        idn_to_ascii,
+        ipv4_syntax,
+        ipv6_syntax,
+        port_number
+    }
+};
+
+/// Schemes that need a host in HTML5 mode.
+const SPECIAL_SCHEMES: [&str; 5] = ["ftp", "http", "https", "ws", "wss"];
+
+/// Component of a URL, as named by RFC 3986.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlPart {
+    Scheme,
+    Userinfo,
+    Host,
+    Port,
+    Path,
+    Query,
+    Fragment,
+}
+
+impl fmt::Display for UrlPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            UrlPart::Scheme => "scheme",
+            UrlPart::Userinfo => "userinfo",
+            UrlPart::Host => "host",
+            UrlPart::Port => "port",
+            UrlPart::Path => "path",
+            UrlPart::Query => "query",
+            UrlPart::Fragment => "fragment",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Error of the [`url`] rule.
+///
+/// Offsets are byte offsets into the checked string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlError {
+    Empty,
+    /// No `scheme:` at the start.
+    MissingScheme,
+    /// A char that is not allowed in `part`.
+    InvalidChar{
+        part: UrlPart,
+        offset: usize,
+        c: char
+    },
+    /// A `%` in `part` that is not followed by two hex digits.
+    PercentEncoding{
+        part: UrlPart,
+        offset: usize
+    },
+    /// The scheme, lowercased, is not one of the allowed schemes.
+    SchemeNotAllowed(String),
+    MissingHost,
+    Host(NetError),
+    Port(NetError),
+    /// `user:password@` before the host, which HTML5 mode rejects.
+    Credentials,
+}
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlError::Empty => write!(f, "must not be empty"),
+            UrlError::MissingScheme => write!(f, "URL must start with a scheme"),
+            UrlError::InvalidChar{part, offset, c} => {
+                write!(f, "URL {} contains {:?} at offset {}", part, c, offset)
+            }
+            UrlError::PercentEncoding{part, offset} => {
+                write!(f, "URL {} has an invalid percent-encoding at offset {}", part, offset)
+            }
+            UrlError::SchemeNotAllowed(scheme) => write!(f, "URL scheme {:?} is not allowed", scheme),
+            UrlError::MissingHost => write!(f, "URL must have a host"),
+            UrlError::Host(e) => write!(f, "URL host: {}", e),
+            UrlError::Port(e) => write!(f, "URL port: {}", e),
+            UrlError::Credentials => write!(f, "URL must not contain credentials"),
+        }
+    }
+}
+
+impl std::error::Error for UrlError {}
+
+/// Options of the [`url`] rule.
+///
+/// ```
+/// # use error_combinator::check::rules::url::UrlOptions;
+/// let options = UrlOptions::html5().schemes(["https"]).idn(true);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UrlOptions {
+    schemes: Option<Vec<String>>,
+    require_host: bool,
+    dns_host: bool,
+    idn: bool,
+    html5: bool,
+}
+
+impl UrlOptions {
+    /// RFC 3986 syntax with any scheme and an optional host, which may be any
+    /// ASCII `reg-name` such as `my_host`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Syntax of `<input type="url">`, stricter than RFC 3986: `http`,
+    /// `https`, `ws`, `wss` and `ftp` URLs need a host, and credentials are
+    /// rejected. Non-ASCII chars are allowed in the path, query and fragment,
+    /// and internationalised host names as with [`UrlOptions::idn`].
+    pub fn html5() -> Self {
+        UrlOptions { html5: true, ..Self::default() }
+    }
+
+    /// Only accepts the given schemes, compared case-insensitively.
+    pub fn schemes<I>(mut self, schemes: I) -> Self
+        where
+            I: IntoIterator<Item: Into<String>>
+    {
+        self.schemes = Some(schemes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Rejects URLs without `//host`, such as `mailto:a@example.com`. The
+    /// host itself is checked as before; see [`UrlOptions::dns_host`].
+    pub fn require_host(mut self, require: bool) -> Self {
+        self.require_host = require;
+        self
+    }
+
+    /// Rejects hosts that are not IP addresses or DNS host names, such as
+    /// `my_host`, which RFC 3986 allows as a `reg-name`. A URL without a host
+    /// still passes unless [`UrlOptions::require_host`] is set.
+    pub fn dns_host(mut self, dns: bool) -> Self {
+        self.dns_host = dns;
+        self
+    }
+
+    /// Accepts internationalised host names, checked in their punycode form.
+    /// Names are lowercased before encoding, without IDNA normalisation or
+    /// mapping.
+    pub fn idn(mut self, idn: bool) -> Self {
+        self.idn = idn;
+        self
+    }
+}
+
+fn is_unreserved(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~')
+}
+
+fn is_sub_delim(c: char) -> bool {
+    matches!(c, '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=')
+}
+
+/// Checks `s[start..end]` for chars allowed in `part`: unreserved chars,
+/// sub-delims, percent-encodings, `extra`, and non-ASCII chars if `unicode`.
+fn component(s: &str, start: usize, end: usize, part: UrlPart, extra: &str, unicode: bool)
+    -> Result<(), UrlError>
+{
+    let bytes = s.as_bytes();
+    for (i, c) in s[start..end].char_indices() {
+        let offset = start + i;
+        if c == '%' {
+            let hex = |j: usize| j < end && bytes[j].is_ascii_hexdigit();
+            if !(hex(offset + 1) && hex(offset + 2)) {
+                return Err(UrlError::PercentEncoding{ part, offset });
+            }
+            continue;
+        }
+        let unicode = unicode && !c.is_ascii() && !c.is_whitespace() && !c.is_control();
+        if !(is_unreserved(c) || is_sub_delim(c) || extra.contains(c) || unicode) {
+            return Err(UrlError::InvalidChar{ part, offset, c });
+        }
+    }
+    Ok(())
+}
+
+/// IPv4 address or registered name. RFC 3986 allows any `reg-name`, while
+/// HTML5 mode, [`UrlOptions::dns_host`] and internationalised domains ask
+/// for a DNS host name.
+fn host_syntax(s: &str, start: usize, end: usize, options: &UrlOptions) -> Result<(), UrlError> {
+    let host = &s[start..end];
+    let dotted = host.bytes().all(|b| b.is_ascii_digit() || b == b'.');
+    let dns = options.html5 || options.dns_host || options.idn;
+    if !dns {
+        if dotted && ipv4_syntax(host).is_ok() {
+            return Ok(());
+        }
+        return component(s, start, end, UrlPart::Host, "", false);
+    }
+    if dotted {
+        ipv4_syntax(host)
+    } else if options.idn || (options.html5 && !host.is_ascii()) {
+        idn_to_ascii(host).and_then(|ascii| hostname_syntax(&ascii))
+    } else {
+        hostname_syntax(host)
+    }
+    .map_err(UrlError::Host)
+}
+
+fn authority(s: &str, start: usize, end: usize, options: &UrlOptions, special: bool)
+    -> Result<(), UrlError>
+{
+    let host_start = match s[start..end].rfind('@') {
+        Some(at) => {
+            if options.html5 {
+                return Err(UrlError::Credentials);
+            }
+            component(s, start, start + at, UrlPart::Userinfo, ":", false)?;
+            start + at + 1
+        }
+        None => start,
+    };
+
+    let port_start = if s[host_start..end].starts_with('[') {
+        let close = s[host_start..end].find(']')
+            .ok_or(UrlError::Host(NetError::NotIpAddress))? + host_start;
+        ipv6_syntax(&s[host_start + 1..close], false).map_err(UrlError::Host)?;
+        match s[close + 1..end].chars().next() {
+            None => None,
+            Some(':') => Some(close + 2),
+            Some(c) => return Err(UrlError::InvalidChar{ part: UrlPart::Host, offset: close + 1, c }),
+        }
+    } else {
+        let port_start = s[host_start..end].rfind(':').map(|i| host_start + i + 1);
+        let host_end = port_start.map_or(end, |p| p - 1);
+        if host_start == host_end {
+            if options.require_host || special {
+                return Err(UrlError::MissingHost);
+            }
+        } else {
+            host_syntax(s, host_start, host_end, options)?;
+        }
+        port_start
+    };
+
+    match port_start {
+        Some(p) if p < end => port_number(&s[p..end], &(0..=u16::MAX))
+            .map(|_| ())
+            .map_err(UrlError::Port),
+        _ => Ok(()),
+    }
+}
+
+fn url_syntax(s: &str, options: &UrlOptions) -> Result<(), UrlError> {
+    if s.is_empty() {
+        return Err(UrlError::Empty);
+    }
+    let colon = match s.find([':', '/', '?', '#']) {
+        Some(i) if 0 < i && s.as_bytes()[i] == b':' => i,
+        _ => return Err(UrlError::MissingScheme),
+    };
+    for (offset, c) in s[..colon].char_indices() {
+        let valid = c.is_ascii_alphabetic()
+            || (0 < offset && (c.is_ascii_digit() || matches!(c, '+' | '-' | '.')));
+        if !valid {
+            return Err(UrlError::InvalidChar{ part: UrlPart::Scheme, offset, c });
+        }
+    }
+    let scheme = s[..colon].to_ascii_lowercase();
+    if let Some(schemes) = &options.schemes
+        && !schemes.iter().any(|allowed| allowed.eq_ignore_ascii_case(&scheme))
+    {
+        return Err(UrlError::SchemeNotAllowed(scheme));
+    }
+    let special = options.html5 && SPECIAL_SCHEMES.contains(&scheme.as_str());
+
+    let fragment = s.find('#');
+    let query = s[..fragment.unwrap_or(s.len())].find('?');
+    let hier_end = query.or(fragment).unwrap_or(s.len());
+
+    let path_start = if s[colon + 1..hier_end].starts_with("//") {
+        let start = colon + 3;
+        let end = s[start..hier_end].find('/').map_or(hier_end, |i| start + i);
+        authority(s, start, end, options, special)?;
+        end
+    } else {
+        if options.require_host || special {
+            return Err(UrlError::MissingHost);
+        }
+        colon + 1
+    };
+
+    let unicode = options.html5;
+    component(s, path_start, hier_end, UrlPart::Path, ":@/", unicode)?;
+    if let Some(q) = query {
+        component(s, q + 1, fragment.unwrap_or(s.len()), UrlPart::Query, ":@/?", unicode)?;
+    }
+    if let Some(f) = fragment {
+        component(s, f + 1, s.len(), UrlPart::Fragment, ":@/?", unicode)?;
+    }
+    Ok(())
+}
+
+/// Absolute URL such as `https://user@example.com:8080/a/b?q=1#top`.
+///
+/// Hosts are IPv4 addresses, bracketed IPv6 addresses or RFC 3986
+/// registered names. In HTML5 mode, with a required host or with
+/// internationalised domains, a registered name must be a DNS host name
+/// (see [`super::net::hostname`]). Chars outside of RFC 3986 must be
+/// percent-encoded, except in HTML5 mode.
+pub fn url<T: AsRef<str>, S>(options: UrlOptions)
+    -> Rule<T, impl Fn(&T) -> Result<(), UrlError> + Clone, S>
+{
+    Rule::new(move |v: &T| url_syntax(v.as_ref(), &options))
+}
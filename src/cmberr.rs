@@ -69,6 +69,24 @@ impl<E> CombineErrorBuilder<Vec<E>, E> for VecCombine<E> {
     }
 }
 
+impl<E> CombineErrorBuilder<E, Vec<E>> for VecCombine<E> {
+    type Combiner = Self;
+    type Out = Vec<E>;
+
+    fn build() -> Self::Combiner {
+        VecCombine { data: Vec::new() }
+    }
+}
+
+impl<E> CombineErrorBuilder<Vec<E>, Vec<E>> for VecCombine<E> {
+    type Combiner = Self;
+    type Out = Vec<E>;
+
+    fn build() -> Self::Combiner {
+        VecCombine { data: Vec::new() }
+    }
+}
+
 impl<T> CombineError<T, T> for VecCombine<T> {
     type Out = Vec<T>;
 
@@ -101,3 +119,34 @@ impl<E> CombineError<Vec<E>, E> for VecCombine<E> {
     }
 }
 
+impl<E> CombineError<E, Vec<E>> for VecCombine<E> {
+    type Out = Vec<E>;
+
+    fn left(&mut self, ea: E) {
+        self.data.push(ea);
+    }
+
+    fn right(&mut self, eb: Vec<E>) {
+        self.data.extend(eb);
+    }
+
+    fn finish(self) -> Self::Out {
+        self.data
+    }
+}
+
+impl<E> CombineError<Vec<E>, Vec<E>> for VecCombine<E> {
+    type Out = Vec<E>;
+
+    fn left(&mut self, ea: Vec<E>) {
+        self.data.extend(ea);
+    }
+
+    fn right(&mut self, eb: Vec<E>) {
+        self.data.extend(eb);
+    }
+
+    fn finish(self) -> Self::Out {
+        self.data
+    }
+}
@@ -11,7 +11,8 @@
 //!     check::{
 //!         Check,        // trait
 //!         CheckOutcome, // struct
-//!         CheckState    // struct
+//!         CheckState,   // struct
+//!         check_ref     // fn
 //!     },
 //! };
 //! 
@@ -41,16 +42,15 @@
 //! -> 
 //! CheckOutcome<&str, ErrState<Checked>, ValidateErr>
 //! {
-//!     if data.value.starts_with("hello") {
-//!         CheckOutcome::Passed(
-//!             CheckState::new(data.value)
-//!         )
-//!     } else {
-//!         CheckOutcome::Failed{
-//!             state: CheckState::new(data.value),
-//!             err: ValidateErr::CheckStartsWithHelloErr
+//!     // the check returns `Ok` or `Err`, and `check_ref` sets the state
+//!     check_ref(|value: &str| {
+//!         if value.starts_with("hello") {
+//!             Ok(())
+//!         } else {
+//!             Err(ValidateErr::CheckStartsWithHelloErr)
 //!         }
-//!     }
+//!     })
+//!     .check(data)
 //! }
 //! 
 //! fn main() {
@@ -76,6 +76,10 @@
 //! 
 //! ```
 
+mod macros;
+
 pub mod check;
 pub mod cmberr;
+pub mod state;
+pub mod validate;
 
@@ -0,0 +1,87 @@
+/// Builds a chain of checks without writing the combiner on every link.
+///
+/// ```
+/// use error_combinator::{
+///     check::{Check, CheckState},
+///     check::rules::string::{contains, ends_with, min_len, starts_with},
+///     checks,
+///     cmberr::VecCombine,
+/// };
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum ValidateErr {
+///     StartsWithHello,
+///     Min6,
+///     EndsWithWorld,
+///     IncludesAbc,
+/// }
+///
+/// let checker = checks![VecCombine<ValidateErr>;
+///     starts_with("hello", ValidateErr::StartsWithHello) & min_len(6usize, ValidateErr::Min6)
+///         | (ends_with("world", ValidateErr::EndsWithWorld) & contains("abc", ValidateErr::IncludesAbc))
+/// ];
+/// let r = checker.check(CheckState::<_, ()>::new("hello world"));
+/// assert_eq!(r.err(), Some(vec![ValidateErr::IncludesAbc]));
+/// ```
+///
+/// - `a & b` expands to `a.and::<_, C>(b)` (stops at the first failure)
+/// - `a | b` expands to `a.or::<_, C>(b)` (runs both and accumulates errors)
+///
+/// `&` binds tighter than `|`, as in Rust: `a | b & c` is `a | (b & c)`.
+/// Operators of the same kind are applied from left to right.
+/// A parenthesized operand is expanded recursively, so use it for grouping.
+/// Any other expression containing `&` or `|` (e.g. a closure) has to be
+/// wrapped in braces: `{ |v| ... }`.
+#[macro_export]
+macro_rules! checks {
+    ($cmb:ty; $($rest:tt)+) => {
+        $crate::__checks!(@or [$cmb] [] [] $($rest)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checks {
+    // operator `|`: close the current `&` chain
+    (@or [$cmb:ty] [$($acc:tt)*] [$($cur:tt)+] | $($rest:tt)*) => {
+        $crate::__checks!(@or [$cmb] [$crate::__checks!(@link [$cmb] [$($acc)*] [$crate::__checks!(@and [$cmb] [] [] $($cur)+)]) , or] [] $($rest)*)
+    };
+    // part of an `&` chain
+    (@or [$cmb:ty] [$($acc:tt)*] [$($cur:tt)*] $tt:tt $($rest:tt)*) => {
+        $crate::__checks!(@or [$cmb] [$($acc)*] [$($cur)* $tt] $($rest)*)
+    };
+    // end of input
+    (@or [$cmb:ty] [$($acc:tt)*] [$($cur:tt)+]) => {
+        $crate::__checks!(@link [$cmb] [$($acc)*] [$crate::__checks!(@and [$cmb] [] [] $($cur)+)])
+    };
+
+    // operator `&`: close the current operand
+    (@and [$cmb:ty] [$($acc:tt)*] [$($cur:tt)+] & $($rest:tt)*) => {
+        $crate::__checks!(@and [$cmb] [$crate::__checks!(@link [$cmb] [$($acc)*] [$crate::__checks!(@operand [$cmb] $($cur)+)]) , and] [] $($rest)*)
+    };
+    // part of an operand
+    (@and [$cmb:ty] [$($acc:tt)*] [$($cur:tt)*] $tt:tt $($rest:tt)*) => {
+        $crate::__checks!(@and [$cmb] [$($acc)*] [$($cur)* $tt] $($rest)*)
+    };
+    // end of the chain
+    (@and [$cmb:ty] [$($acc:tt)*] [$($cur:tt)+]) => {
+        $crate::__checks!(@link [$cmb] [$($acc)*] [$crate::__checks!(@operand [$cmb] $($cur)+)])
+    };
+
+    // first operand
+    (@link [$cmb:ty] [] [$cur:expr]) => {
+        $cur
+    };
+    // `acc op cur`
+    (@link [$cmb:ty] [$acc:expr , $op:ident] [$cur:expr]) => {
+        $crate::check::Check::$op::<_, $cmb>($acc, $cur)
+    };
+
+    // grouping
+    (@operand [$cmb:ty] ( $($inner:tt)+ )) => {
+        $crate::checks!($cmb; $($inner)+)
+    };
+    (@operand [$cmb:ty] $($cur:tt)+) => {
+        $($cur)+
+    };
+}
@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+
+/// Marker for a check that has already been performed.
+pub struct Checked;
+
+/// Marker for a check that has not been performed yet.
+pub struct Unchecked;
+
+/// State of a value handed back by a failed check.
+///
+/// Nothing is known about such a value, whatever the check would have
+/// guaranteed had it passed.
+pub struct Unverified;
+
+// ======================= Type-level set of check tags =======================
+
+/// Empty set of check tags: no check has been performed yet.
+pub struct Nil;
+
+/// Set of check tags: `H` on top of the set `T`.
+///
+/// A check that only cares about some of the performed checks is written
+/// generic over the set it receives and adds its own tag on top of it.
+///
+/// ```
+/// use error_combinator::{
+///     check::{check_ref, Check, CheckOutcome, CheckState},
+///     state::{Cons, Has},
+///     States,
+/// };
+///
+/// struct HelloChecked;
+/// struct Min6Checked;
+///
+/// fn check_hello<S>(data: CheckState<&str, S>)
+///     -> CheckOutcome<&str, Cons<HelloChecked, S>, &'static str>
+/// {
+///     check_ref::<str, S, Cons<HelloChecked, S>, _, _>(|s: &str| {
+///         if s.starts_with("hello") { Ok(()) } else { Err("hello") }
+///     })
+///     .check(data)
+/// }
+///
+/// fn check_min6<S, I>(data: CheckState<&str, S>)
+///     -> CheckOutcome<&str, Cons<Min6Checked, S>, &'static str>
+/// where
+///     S: Has<HelloChecked, I>
+/// {
+///     check_ref::<str, S, Cons<Min6Checked, S>, _, _>(|s: &str| {
+///         if 6 < s.len() { Ok(()) } else { Err("min6") }
+///     })
+///     .check(data)
+/// }
+///
+/// let r = check_hello
+///     .and::<_, error_combinator::cmberr::DefaultCombine<_>>(check_min6)
+///     .check(CheckState::<_, States![]>::new("hello world"));
+/// assert!(r.is_passed());
+/// ```
+pub struct Cons<H, T>(PhantomData<(H, T)>);
+
+/// Builds a set of check tags: `States![A, B]` is `Cons<A, Cons<B, Nil>>`.
+#[macro_export]
+macro_rules! States {
+    () => {
+        $crate::state::Nil
+    };
+    ($head:ty $(, $tail:ty)* $(,)?) => {
+        $crate::state::Cons<$head, $crate::States![$($tail),*]>
+    };
+}
+
+/// Index of a tag found at the head of a set.
+pub struct Here;
+
+/// Index of a tag found in the tail of a set.
+pub struct There<I>(PhantomData<I>);
+
+/// Implemented by state types in which the check `Tag` has been performed.
+///
+/// For the sets built from [`Cons`], `Index` is inferred by the compiler and
+/// only exists so that the implementations do not overlap. A bound must leave
+/// it generic, `S: Has<Tag, I>`: a fixed index would only match one position
+/// in the set. User-defined state structs implement `Has<Tag, Here>`, see
+/// [`Set`].
+pub trait Has<Tag, Index> {}
+
+/// Implemented by state types that can record the check `Tag`.
+///
+/// `Output` is the state after the check has been performed. Together with
+/// [`Has`], this lets a check require some states and set another one while
+/// staying generic over every other state parameter.
+///
+/// ```
+/// use error_combinator::{
+///     check::{check_ref, Check, CheckOutcome, CheckState},
+///     state::{Checked, Has, Here, Mark, Set, Unchecked},
+/// };
+/// use std::marker::PhantomData;
+///
+/// struct ErrState<Min6, IncludesAbc>(PhantomData<(Min6, IncludesAbc)>);
+///
+/// struct Min6;
+/// struct IncludesAbc;
+///
+/// impl<B> Has<Min6, Here> for ErrState<Checked, B> {}
+/// impl<A, B> Set<IncludesAbc> for ErrState<A, B> {
+///     type Output = ErrState<A, Checked>;
+/// }
+///
+/// fn check_includes_abc<S, I>(data: CheckState<&str, S>)
+///     -> CheckOutcome<&str, Mark<S, IncludesAbc>, &'static str>
+/// where
+///     S: Has<Min6, I> + Set<IncludesAbc>
+/// {
+///     check_ref::<str, S, Mark<S, IncludesAbc>, _, _>(|s: &str| {
+///         if s.contains("abc") { Ok(()) } else { Err("abc") }
+///     })
+///     .check(data)
+/// }
+///
+/// let r = check_includes_abc(CheckState::<_, ErrState<Checked, Unchecked>>::new("abcdefg"));
+/// assert!(r.is_passed());
+/// ```
+pub trait Set<Tag> {
+    type Output;
+}
+
+/// State after the check `Tag` has been performed on the state `S`.
+pub type Mark<S, Tag> = <S as Set<Tag>>::Output;
+
+impl<Tag, T> Has<Tag, Here> for Cons<Tag, T> {}
+
+impl<Tag, H, T, I> Has<Tag, There<I>> for Cons<H, T>
+where
+    T: Has<Tag, I>,
+{}
+
+impl<Tag> Set<Tag> for Nil {
+    type Output = Cons<Tag, Nil>;
+}
+
+impl<Tag, H, T> Set<Tag> for Cons<H, T> {
+    type Output = Cons<Tag, Cons<H, T>>;
+}
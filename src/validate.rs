@@ -0,0 +1,45 @@
+use crate::check::{
+    Check,
+    CheckState,
+    Validated,
+    Validator
+};
+
+#[cfg(feature = "derive")]
+pub use error_combinator_derive::Validate;
+
+/// Types that know how to build their own validation pipeline.
+///
+/// Usually implemented with `#[derive(Validate)]` (requires the `derive`
+/// feature), which generates the typestate struct and chains the
+/// `#[check(fn_name)]` functions of each field.
+pub trait Validate: Sized {
+    /// State before any check has been performed
+    type Unchecked;
+    /// State after every check has passed
+    type Checked;
+    type Error;
+
+    /// Pipeline that stops at the first failing check (built from `And`)
+    fn check_first<'a>()
+        -> impl Validator<&'a Self, Self::Unchecked, PostState = Self::Checked, Error = Self::Error>
+        where Self: 'a;
+
+    /// Pipeline that runs every check and accumulates errors (built from `Or`)
+    fn check_all<'a>()
+        -> impl Validator<&'a Self, Self::Unchecked, PostState = Self::Checked, Error = Self::Error>
+        where Self: 'a;
+
+    fn validate(&self) -> Result<&Self, Self::Error> {
+        Self::check_all()
+            .check(CheckState::new(self))
+            .to_result()
+    }
+
+    /// Like [`Validate::validate`], but keeps the proof that every check passed
+    fn validated(&self) -> Result<Validated<&Self, Self::Checked>, Self::Error> {
+        Self::check_all()
+            .check(CheckState::new(self))
+            .into_validated()
+    }
+}
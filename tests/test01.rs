@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests_either {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, check_ref
+        },
+        cmberr::{
+            VecCombine
+        }
+    };
+
+    struct ContactUnchecked;
+    struct ContactChecked;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        InvalidPhoneErr,
+        InvalidEmailErr,
+    }
+
+    fn check_phone(data: &str) -> Result<(), ValidateErr> {
+        if !data.is_empty() && data.chars().all(|c| c.is_ascii_digit() || c == '-') {
+            Ok(())
+        } else {
+            Err(ValidateErr::InvalidPhoneErr)
+        }
+    }
+
+    fn check_email(data: &str) -> Result<(), ValidateErr> {
+        if data.contains('@') {
+            Ok(())
+        } else {
+            Err(ValidateErr::InvalidEmailErr)
+        }
+    }
+
+    /// either chain test
+    #[test]
+    fn either_works00() {
+        let check_list  = [
+            ("090-1234-5678",    None),
+            ("tom@example.com",  None),
+            ("hello",            Some(vec![ValidateErr::InvalidPhoneErr, ValidateErr::InvalidEmailErr])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker =
+                check_ref::<str, ContactUnchecked, ContactChecked, _, _>(check_phone)
+                .either::<_, VecCombine<ValidateErr>>(check_ref(check_email));
+
+            let r = checker.check(
+                CheckState::new(s)
+            );
+
+            println!("test case: \"{}\"", s);
+            match r {
+                CheckOutcome::Passed(_v) => {
+                    assert!(ans.is_none());
+                }
+                CheckOutcome::Failed{state:_, err} => {
+                    println!("{:?}", err);
+                    assert_eq!(ans, Some(err));
+                }
+            }
+        }
+    }
+}
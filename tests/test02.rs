@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests_not {
+    use error_combinator::{
+        check::{
+            Check, CheckState, check_ref
+        },
+        cmberr::{
+            VecCombine
+        }
+    };
+
+    struct Unchecked;
+    struct Checked;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        StartsWithHello,
+        ContainsForbiddenWord(String),
+    }
+
+    fn check_starts_with_hello(data: &str) -> Result<(), ValidateErr> {
+        if data.starts_with("hello") {
+            Ok(())
+        } else {
+            Err(ValidateErr::StartsWithHello)
+        }
+    }
+
+    fn check_contains_damn(data: &str) -> Result<(), ValidateErr> {
+        if data.contains("damn") {
+            Ok(())
+        } else {
+            Err(ValidateErr::ContainsForbiddenWord("damn".to_string()))
+        }
+    }
+
+    /// not combined with and chain test
+    #[test]
+    fn not_works00() {
+        let check_list  = [
+            ("hello world",      None),
+            ("hello damn world", Some(vec![ValidateErr::ContainsForbiddenWord("hello damn world".to_string())])),
+            ("damn world",       Some(vec![ValidateErr::StartsWithHello])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker =
+                check_ref::<str, Unchecked, Unchecked, _, _>(check_starts_with_hello)
+                .and::<_, VecCombine<ValidateErr>>(
+                    check_ref::<str, Unchecked, Checked, _, _>(check_contains_damn)
+                    .not(|v: &&str| ValidateErr::ContainsForbiddenWord(v.to_string()))
+                );
+
+            let r = checker.check(
+                CheckState::new(s)
+            );
+
+            println!("test case: \"{}\"", s);
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests_checks_macro {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, check_ref
+        },
+        cmberr::{
+            VecCombine
+        },
+        checks
+    };
+    use std::marker::PhantomData;
+
+    struct Checked;
+    struct Unchecked;
+    struct ErrState<CheckStartsWithHello, CheckMin3, CheckEndsWithWorld, CheckIncludesAbc> {
+        _check_starts_with_hello: PhantomData<CheckStartsWithHello>,
+        _check_min3: PhantomData<CheckMin3>,
+        _check_ends_with_world: PhantomData<CheckEndsWithWorld>,
+        _check_includes_abc: PhantomData<CheckIncludesAbc>
+    }
+
+    #[allow(clippy::enum_variant_names)]
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        CheckStartsWithHelloErr,
+        CheckMin6Err,
+        CheckEndsWithWorldErr,
+        CheckIncludesAbcErr,
+    }
+
+    fn check_starts_with_hello(
+        data: CheckState<&str, ErrState<Unchecked, Unchecked, Unchecked, Unchecked>>) 
+    -> 
+    CheckOutcome<&str, ErrState<Checked, Unchecked, Unchecked, Unchecked>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.starts_with("hello") {
+                Ok(())
+            } else {
+                Err(ValidateErr::CheckStartsWithHelloErr)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_min6(
+        data: CheckState<&str, ErrState<Checked, Unchecked, Unchecked, Unchecked>>) 
+    -> 
+    CheckOutcome<&str, ErrState<Checked, Checked, Unchecked, Unchecked>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if 6 < value.len() {
+                Ok(())
+            } else {
+                Err(ValidateErr::CheckMin6Err)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_ends_with_world(
+        data: CheckState<&str, ErrState<Checked, Checked, Unchecked, Unchecked>>) 
+    -> 
+    CheckOutcome<&str, ErrState<Checked, Checked, Checked, Unchecked>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.ends_with("world") {
+                Ok(())
+            } else {
+                Err(ValidateErr::CheckEndsWithWorldErr)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_includes_abc(
+        data: CheckState<&str, ErrState<Checked, Checked, Checked, Unchecked>>) 
+    -> 
+    CheckOutcome<&str, ErrState<Checked, Checked, Checked, Checked>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.contains("abc") {
+                Ok(())
+            } else {
+                Err(ValidateErr::CheckIncludesAbcErr)
+            }
+        })
+        .check(data)
+    }
+
+    /// `&` chain test
+    #[test]
+    fn checks_works00() {
+        let check_list  = [
+            ("hello abc world", None),
+            ("abc world",       Some(vec![ValidateErr::CheckStartsWithHelloErr])),
+            ("hello world",     Some(vec![ValidateErr::CheckIncludesAbcErr])),
+            ("hello--",         Some(vec![ValidateErr::CheckEndsWithWorldErr])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker = checks![VecCombine<ValidateErr>;
+                check_starts_with_hello & check_min6 & check_ends_with_world & check_includes_abc
+            ];
+
+            let r = checker.check(
+                CheckState::new(s)
+            );
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// mixed `&` / `|` chain test with grouping
+    #[test]
+    fn checks_works01() {
+        let check_list  = [
+            ("hello abc world", None),
+            ("abc world",       Some(vec![ValidateErr::CheckStartsWithHelloErr])),
+            ("hello--",         Some(vec![ValidateErr::CheckEndsWithWorldErr])),
+            ("a",               Some(vec![ValidateErr::CheckStartsWithHelloErr, ValidateErr::CheckMin6Err, ValidateErr::CheckEndsWithWorldErr])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker = checks![VecCombine<ValidateErr>;
+                (check_starts_with_hello | check_min6) | (check_ends_with_world & check_includes_abc)
+            ];
+
+            let r = checker.check(
+                CheckState::new(s)
+            );
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// `&` binds tighter than `|`: `a | b & c | d` is `a | (b & c) | d`
+    #[test]
+    fn checks_works02() {
+        let check_list  = [
+            ("hello abc world", None),
+            ("abc hello",       Some(vec![ValidateErr::CheckStartsWithHelloErr, ValidateErr::CheckEndsWithWorldErr])),
+            ("hello",           Some(vec![ValidateErr::CheckMin6Err, ValidateErr::CheckIncludesAbcErr])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker = checks![VecCombine<ValidateErr>;
+                check_starts_with_hello | check_min6 & check_ends_with_world | check_includes_abc
+            ];
+
+            let r = checker.check(
+                CheckState::new(s)
+            );
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+}
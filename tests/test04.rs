@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests_state_set {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, Validated, check_ref
+        },
+        cmberr::{
+            VecCombine
+        },
+        state::{
+            Cons, Has, Nil
+        },
+        States
+    };
+
+    struct HelloChecked;
+    struct Min6Checked;
+    struct WorldChecked;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        StartsWithHello,
+        Min6,
+        EndsWithWorld,
+    }
+
+    fn check_starts_with_hello<S>(
+        data: CheckState<&str, S>)
+    ->
+    CheckOutcome<&str, Cons<HelloChecked, S>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.starts_with("hello") {
+                Ok(())
+            } else {
+                Err(ValidateErr::StartsWithHello)
+            }
+        })
+        .check(data)
+    }
+
+    /// requires `HelloChecked`, whatever else has been checked
+    fn check_min6<S, I>(
+        data: CheckState<&str, S>)
+    ->
+    CheckOutcome<&str, Cons<Min6Checked, S>, ValidateErr>
+    where
+        S: Has<HelloChecked, I>
+    {
+        check_ref(|value: &str| {
+            if 6 < value.len() {
+                Ok(())
+            } else {
+                Err(ValidateErr::Min6)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_ends_with_world<S>(
+        data: CheckState<&str, S>)
+    ->
+    CheckOutcome<&str, Cons<WorldChecked, S>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.ends_with("world") {
+                Ok(())
+            } else {
+                Err(ValidateErr::EndsWithWorld)
+            }
+        })
+        .check(data)
+    }
+
+    /// only accepts values that went through all three checks
+    fn accept<S, I0, I1, I2>(_: Validated<&str, S>)
+    where
+        S: Has<HelloChecked, I0> + Has<Min6Checked, I1> + Has<WorldChecked, I2>
+    {}
+
+    #[test]
+    fn state_set_works00() {
+        let check_list  = [
+            ("hello world", None),
+            ("hello",       Some(vec![ValidateErr::Min6, ValidateErr::EndsWithWorld])),
+            ("abc",         Some(vec![ValidateErr::StartsWithHello, ValidateErr::Min6, ValidateErr::EndsWithWorld])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker =
+                check_starts_with_hello
+                .or::<_, VecCombine<ValidateErr>>(check_min6)
+                .or::<_, VecCombine<ValidateErr>>(check_ends_with_world);
+
+            let r = checker.check(CheckState::<_, Nil>::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// `check_min6` can run anywhere after `check_starts_with_hello`
+    #[test]
+    fn state_set_works01() {
+        let check_list  = [
+            ("hello world", None),
+            ("hello",       Some(vec![ValidateErr::EndsWithWorld, ValidateErr::Min6])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker =
+                check_starts_with_hello
+                .or::<_, VecCombine<ValidateErr>>(check_ends_with_world)
+                .or::<_, VecCombine<ValidateErr>>(check_min6);
+
+            match checker.check(CheckState::<_, States![]>::new(s)) {
+                CheckOutcome::Passed(v) => {
+                    assert!(ans.is_none());
+                    accept(v);
+                }
+                CheckOutcome::Failed{state:_, err} => {
+                    assert_eq!(Some(err), ans);
+                }
+            }
+        }
+    }
+}
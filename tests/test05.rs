@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests_partial_state {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, Validated, check_ref
+        },
+        cmberr::{
+            VecCombine
+        },
+        state::{
+            Checked, Has, Here, Mark, Nil, Set, Unchecked
+        }
+    };
+    use std::marker::PhantomData;
+
+    struct ErrState<CheckStartsWithHello, CheckMin6, CheckIncludesAbc> {
+        _check_starts_with_hello: PhantomData<CheckStartsWithHello>,
+        _check_min6: PhantomData<CheckMin6>,
+        _check_includes_abc: PhantomData<CheckIncludesAbc>
+    }
+
+    struct StartsWithHello;
+    struct Min6;
+    struct IncludesAbc;
+
+    impl<B, C> Has<StartsWithHello, Here> for ErrState<Checked, B, C> {}
+    impl<A, C> Has<Min6, Here> for ErrState<A, Checked, C> {}
+    impl<A, B> Has<IncludesAbc, Here> for ErrState<A, B, Checked> {}
+
+    impl<A, B, C> Set<StartsWithHello> for ErrState<A, B, C> {
+        type Output = ErrState<Checked, B, C>;
+    }
+    impl<A, B, C> Set<Min6> for ErrState<A, B, C> {
+        type Output = ErrState<A, Checked, C>;
+    }
+    impl<A, B, C> Set<IncludesAbc> for ErrState<A, B, C> {
+        type Output = ErrState<A, B, Checked>;
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        StartsWithHello,
+        Min6,
+        IncludesAbc,
+    }
+
+    fn check_starts_with_hello<S: Set<StartsWithHello>>(
+        data: CheckState<&str, S>)
+    ->
+    CheckOutcome<&str, Mark<S, StartsWithHello>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.starts_with("hello") {
+                Ok(())
+            } else {
+                Err(ValidateErr::StartsWithHello)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_min6<S: Set<Min6>>(
+        data: CheckState<&str, S>)
+    ->
+    CheckOutcome<&str, Mark<S, Min6>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if 6 < value.len() {
+                Ok(())
+            } else {
+                Err(ValidateErr::Min6)
+            }
+        })
+        .check(data)
+    }
+
+    /// requires `Min6` to be checked and sets `IncludesAbc`
+    fn check_includes_abc<S: Has<Min6, I> + Set<IncludesAbc>, I>(
+        data: CheckState<&str, S>)
+    ->
+    CheckOutcome<&str, Mark<S, IncludesAbc>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.contains("abc") {
+                Ok(())
+            } else {
+                Err(ValidateErr::IncludesAbc)
+            }
+        })
+        .check(data)
+    }
+
+    fn accept<S, I, J, K>(_: Validated<&str, S>)
+    where
+        S: Has<StartsWithHello, I> + Has<Min6, J> + Has<IncludesAbc, K>
+    {}
+
+    #[test]
+    fn partial_state_works00() {
+        let check_list  = [
+            ("hello abc", None),
+            ("abc",       Some(vec![ValidateErr::StartsWithHello, ValidateErr::Min6])),
+            ("hello xyz", Some(vec![ValidateErr::IncludesAbc])),
+        ];
+
+        for (s, ans) in check_list {
+            // the same checks in two different orders
+            let checker0 =
+                check_starts_with_hello
+                .or::<_, VecCombine<ValidateErr>>(check_min6)
+                .and::<_, VecCombine<ValidateErr>>(check_includes_abc);
+            let checker1 =
+                check_min6
+                .and::<_, VecCombine<ValidateErr>>(check_includes_abc)
+                .and::<_, VecCombine<ValidateErr>>(check_starts_with_hello);
+
+            let r0 = checker0.check(CheckState::<_, ErrState<Unchecked, Unchecked, Unchecked>>::new(s));
+            let r1 = checker1.check(CheckState::<_, ErrState<Unchecked, Unchecked, Unchecked>>::new(s));
+
+            match r0 {
+                CheckOutcome::Passed(v) => {
+                    assert!(ans.is_none());
+                    accept(v);
+                }
+                CheckOutcome::Failed{state:_, err} => {
+                    assert_eq!(Some(err), ans);
+                }
+            }
+            assert_eq!(r1.to_result().is_ok(), ans.is_none());
+        }
+    }
+
+    /// `Set` also works on the type-level sets
+    #[test]
+    fn partial_state_works01() {
+        let r = check_starts_with_hello(CheckState::<_, Nil>::new("hello"));
+        assert!(r.to_result().is_ok());
+    }
+}
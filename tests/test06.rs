@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests_validated {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, Validated, check_ref
+        },
+        cmberr::{
+            VecCombine
+        },
+        state::{
+            Cons, Has, Nil, Unverified
+        }
+    };
+    use std::cell::RefCell;
+
+    struct HelloChecked;
+    struct WorldChecked;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        StartsWithHello,
+        EndsWithWorld,
+    }
+
+    fn check_starts_with_hello<S>(
+        data: CheckState<&str, S>)
+    ->
+    CheckOutcome<&str, Cons<HelloChecked, S>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.starts_with("hello") {
+                Ok(())
+            } else {
+                Err(ValidateErr::StartsWithHello)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_ends_with_world<S>(
+        data: CheckState<&str, S>)
+    ->
+    CheckOutcome<&str, Cons<WorldChecked, S>, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.ends_with("world") {
+                Ok(())
+            } else {
+                Err(ValidateErr::EndsWithWorld)
+            }
+        })
+        .check(data)
+    }
+
+    /// only accepts values proven to start with "hello"
+    fn save<S, I>(v: Validated<&str, S>) -> String
+    where
+        S: Has<HelloChecked, I>
+    {
+        assert_eq!(v.len(), v.as_ref().len());
+        v.into_inner().to_string()
+    }
+
+    #[test]
+    fn validated_works00() {
+        let check_list  = [
+            ("hello world", Ok("hello world".to_string())),
+            ("hello",       Err(vec![ValidateErr::EndsWithWorld])),
+            ("abc",         Err(vec![ValidateErr::StartsWithHello, ValidateErr::EndsWithWorld])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker =
+                check_starts_with_hello
+                .or::<_, VecCombine<ValidateErr>>(check_ends_with_world);
+
+            let r = checker
+                .check(CheckState::<_, Nil>::new(s))
+                .into_validated()
+                .map(save);
+            assert_eq!(r, ans);
+        }
+    }
+
+    /// a failed outcome never carries the post-check state
+    #[test]
+    fn validated_works01() {
+        let checker =
+            check_starts_with_hello
+            .and::<_, VecCombine<ValidateErr>>(check_ends_with_world);
+
+        match checker.check(CheckState::<_, Nil>::new("hello")) {
+            CheckOutcome::Passed(_v) => {
+                panic!("Failed!")
+            }
+            CheckOutcome::Failed{state, err} => {
+                let state: CheckState<&str, Unverified> = state;
+                assert_eq!(state.value, "hello");
+                assert_eq!(err, vec![ValidateErr::EndsWithWorld]);
+            }
+        }
+    }
+
+    /// `or` runs its right side at the left post-state even when the left
+    /// side failed; the right error is collected and no proof escapes
+    #[test]
+    fn validated_works02() {
+        let seen = RefCell::new(Vec::new());
+        let check_after_hello = |data: CheckState<&'static str, Cons<HelloChecked, Nil>>| {
+            seen.borrow_mut().push(data.value);
+            check_ends_with_world(data)
+        };
+        let checker =
+            check_starts_with_hello
+            .or::<_, VecCombine<ValidateErr>>(check_after_hello);
+
+        match checker.check(CheckState::<_, Nil>::new("abc world")) {
+            CheckOutcome::Passed(_v) => {
+                panic!("Passed!")
+            }
+            CheckOutcome::Failed{state, err} => {
+                let state: CheckState<&str, Unverified> = state;
+                assert_eq!(state.value, "abc world");
+                assert_eq!(err, vec![ValidateErr::StartsWithHello]);
+            }
+        }
+        assert_eq!(*seen.borrow(), ["abc world"]);
+    }
+}
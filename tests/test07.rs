@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests_dynamic {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator,
+            dynamic::{
+                AllOf, AnyOf, BoxedCheck, dyn_ref
+            }
+        },
+        cmberr::{
+            VecCombine
+        }
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        StartsWithHello,
+        Min6,
+        EndsWithWorld,
+    }
+
+    fn check_starts_with_hello(data: &str) -> Result<(), ValidateErr> {
+        if data.starts_with("hello") { Ok(()) } else { Err(ValidateErr::StartsWithHello) }
+    }
+
+    fn check_min6(data: &str) -> Result<(), ValidateErr> {
+        if 6 < data.len() { Ok(()) } else { Err(ValidateErr::Min6) }
+    }
+
+    fn check_ends_with_world(data: &str) -> Result<(), ValidateErr> {
+        if data.ends_with("world") { Ok(()) } else { Err(ValidateErr::EndsWithWorld) }
+    }
+
+    /// rules toggled at runtime, e.g. per tenant
+    fn build<'a>(hello: bool, min6: bool, world: bool) -> AllOf<&'a str, ValidateErr> {
+        let mut rules = AllOf::new();
+        if hello {
+            rules.push(dyn_ref(check_starts_with_hello));
+        }
+        if min6 {
+            rules.push(dyn_ref(check_min6));
+        }
+        if world {
+            rules.push(dyn_ref(check_ends_with_world));
+        }
+        rules
+    }
+
+    #[test]
+    fn dynamic_works00() {
+        let check_list  = [
+            ((true, true, true),    "hello",       Some(vec![ValidateErr::Min6, ValidateErr::EndsWithWorld])),
+            ((true, false, true),   "hello",       Some(vec![ValidateErr::EndsWithWorld])),
+            ((false, false, false), "hello",       None),
+            ((true, true, true),    "hello world", None),
+        ];
+
+        for ((hello, min6, world), s, ans) in check_list {
+            let input = s.to_string();
+            let r = build(hello, min6, world).check(CheckState::new(input.as_str()));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    #[test]
+    fn dynamic_works01() {
+        let check_list  = [
+            ("hello",   None),
+            ("abc",     Some(vec![vec![ValidateErr::StartsWithHello, ValidateErr::EndsWithWorld]])),
+            ("abcdefg", Some(vec![vec![ValidateErr::StartsWithHello, ValidateErr::EndsWithWorld]])),
+        ];
+
+        for (s, ans) in check_list {
+            let any = AnyOf::<&str, ValidateErr>::new()
+                .with(dyn_ref(check_starts_with_hello))
+                .with(dyn_ref(check_ends_with_world));
+            let checker = AllOf::<&str, Vec<ValidateErr>>::new()
+                .with(any);
+
+            let r = checker.check(CheckState::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// boxed checks still compose with the static combinators
+    #[test]
+    fn dynamic_works02() {
+        let check_list  = [
+            ("hello world", None),
+            ("abc",         Some(vec![ValidateErr::StartsWithHello, ValidateErr::Min6])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker =
+                BoxedCheck::<&str, ValidateErr>::new(dyn_ref(check_starts_with_hello))
+                .or::<_, VecCombine<ValidateErr>>(BoxedCheck::new(dyn_ref(check_min6)));
+
+            let r = checker.check(CheckState::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// an empty `AnyOf` fails, and pipelines can be shared between threads
+    #[test]
+    fn dynamic_works03() {
+        let any = AnyOf::<&str, ValidateErr>::new();
+        assert_eq!(any.check(CheckState::new("hello")).to_result().err(), Some(vec![]));
+
+        let checker = std::sync::Arc::new(
+            AllOf::<&str, ValidateErr>::new()
+                .with(dyn_ref(check_starts_with_hello))
+                .with(dyn_ref(check_min6))
+        );
+        let handles: Vec<_> = ["hello world", "abc"].into_iter()
+            .map(|s| {
+                let checker = std::sync::Arc::clone(&checker);
+                std::thread::spawn(move || checker.validate(CheckState::new(s)).to_result().err())
+            })
+            .collect();
+        let rs: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(rs, [None, Some(vec![ValidateErr::StartsWithHello, ValidateErr::Min6])]);
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests_validator {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator, check_ref
+        },
+        cmberr::{
+            VecCombine
+        }
+    };
+    use std::{sync::Arc, thread};
+
+    struct Checked;
+    struct Unchecked;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        StartsWithHello,
+        Min6,
+        EndsWithWorld,
+    }
+
+    fn check_starts_with_hello(data: &str) -> Result<(), ValidateErr> {
+        if data.starts_with("hello") { Ok(()) } else { Err(ValidateErr::StartsWithHello) }
+    }
+
+    fn check_min6(data: &str) -> Result<(), ValidateErr> {
+        if 6 < data.len() { Ok(()) } else { Err(ValidateErr::Min6) }
+    }
+
+    fn check_ends_with_world(data: &str) -> Result<(), ValidateErr> {
+        if data.ends_with("world") { Ok(()) } else { Err(ValidateErr::EndsWithWorld) }
+    }
+
+    /// the pipeline is built once and used for every value
+    #[test]
+    fn validator_works00() {
+        let check_list  = [
+            ("hello world", None),
+            ("hello",       Some(vec![ValidateErr::Min6, ValidateErr::EndsWithWorld])),
+            ("abc",         Some(vec![ValidateErr::StartsWithHello, ValidateErr::Min6, ValidateErr::EndsWithWorld])),
+        ];
+
+        let checker =
+            check_ref::<str, Unchecked, Unchecked, _, _>(check_starts_with_hello)
+            .or::<_, VecCombine<ValidateErr>>(check_ref::<str, Unchecked, Unchecked, _, _>(check_min6))
+            .or::<_, VecCombine<ValidateErr>>(check_ref::<str, Unchecked, Checked, _, _>(check_ends_with_world));
+
+        for (s, ans) in check_list {
+            let r = checker.validate(CheckState::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// one pipeline shared between threads
+    #[test]
+    fn validator_works01() {
+        let checker = Arc::new(
+            check_ref::<str, Unchecked, Unchecked, _, _>(check_starts_with_hello)
+            .and::<_, VecCombine<ValidateErr>>(check_ref::<str, Unchecked, Checked, _, _>(check_ends_with_world))
+        );
+
+        thread::scope(|scope| {
+            for (s, ok) in [("hello world", true), ("hello", false), ("world", false)] {
+                let checker = Arc::clone(&checker);
+                scope.spawn(move || {
+                    let input = s.to_string();
+                    let r = checker.validate(CheckState::new(input.as_str()));
+                    assert_eq!(r.to_result().is_ok(), ok);
+                });
+            }
+        });
+
+        // still usable as a consuming `Check`
+        let checker = Arc::try_unwrap(checker).ok().unwrap();
+        assert!(checker.check(CheckState::new("hello world")).to_result().is_ok());
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests_async {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, check_ref,
+            async_check::{
+                AsyncCheck, lift
+            }
+        },
+        cmberr::{
+            VecCombine
+        }
+    };
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker}
+    };
+
+    /// minimal executor: polls the future until it is ready
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = pin!(fut);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) {
+                return v;
+            }
+        }
+    }
+
+    /// future that is pending once before completing, like an I/O call
+    async fn yield_now() {
+        let mut yielded = false;
+        std::future::poll_fn(|cx| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }).await
+    }
+
+    struct Unchecked;
+    struct Checked;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        Empty,
+        AlreadyTaken,
+        Blocked,
+    }
+
+    fn check_not_empty(data: &str) -> Result<(), ValidateErr> {
+        if data.is_empty() { Err(ValidateErr::Empty) } else { Ok(()) }
+    }
+
+    /// pretends to look the name up in a database
+    async fn check_unique(
+        data: CheckState<&str, Unchecked>
+    ) -> CheckOutcome<&str, Unchecked, ValidateErr> {
+        yield_now().await;
+        check_ref(|value: &str| {
+            if value == "tom" { Err(ValidateErr::AlreadyTaken) } else { Ok(()) }
+        })
+        .check(data)
+    }
+
+    /// pretends to ask a remote blocklist
+    async fn check_not_blocked(
+        data: CheckState<&str, Unchecked>
+    ) -> CheckOutcome<&str, Checked, ValidateErr> {
+        yield_now().await;
+        check_ref(|value: &str| {
+            if value.contains("admin") { Err(ValidateErr::Blocked) } else { Ok(()) }
+        })
+        .check(data)
+    }
+
+    #[test]
+    fn async_works00() {
+        let check_list  = [
+            ("alice", None),
+            ("",      Some(vec![ValidateErr::Empty])),
+            ("tom",   Some(vec![ValidateErr::AlreadyTaken])),
+            ("admin", Some(vec![ValidateErr::Blocked])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker =
+                lift(check_ref::<str, Unchecked, Unchecked, _, _>(check_not_empty))
+                .and::<_, VecCombine<ValidateErr>>(check_unique)
+                .and::<_, VecCombine<ValidateErr>>(check_not_blocked);
+
+            let r = block_on(checker.check(CheckState::new(s)));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    #[test]
+    fn async_works01() {
+        let check_list  = [
+            ("alice",    None),
+            ("tomadmin", Some(vec![ValidateErr::Blocked])),
+            ("tom",      Some(vec![ValidateErr::AlreadyTaken])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker =
+                check_unique
+                .or::<_, VecCombine<ValidateErr>>(check_not_blocked);
+
+            let r = block_on(checker.check(CheckState::new(s)));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+}
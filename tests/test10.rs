@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests_par_or {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator, check_ref
+        },
+        cmberr::{
+            VecCombine
+        }
+    };
+    use std::{
+        sync::{Mutex, mpsc},
+        time::Duration
+    };
+
+    struct Unchecked;
+    struct Checked;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        StartsWithHello,
+        EndsWithWorld,
+        NotConcurrent,
+    }
+
+    fn check_starts_with_hello(data: &str) -> Result<(), ValidateErr> {
+        if data.starts_with("hello") { Ok(()) } else { Err(ValidateErr::StartsWithHello) }
+    }
+
+    fn check_ends_with_world(data: &str) -> Result<(), ValidateErr> {
+        if data.ends_with("world") { Ok(()) } else { Err(ValidateErr::EndsWithWorld) }
+    }
+
+    #[test]
+    fn par_or_works00() {
+        let check_list  = [
+            ("hello world", None),
+            ("hello",       Some(vec![ValidateErr::EndsWithWorld])),
+            ("world",       Some(vec![ValidateErr::StartsWithHello])),
+            ("abc",         Some(vec![ValidateErr::StartsWithHello, ValidateErr::EndsWithWorld])),
+        ];
+
+        let checker =
+            check_ref::<str, Unchecked, Checked, _, _>(check_starts_with_hello)
+            .par_or::<_, VecCombine<ValidateErr>>(check_ref::<str, Unchecked, Checked, _, _>(check_ends_with_world));
+
+        for (s, ans) in check_list {
+            let r = checker.validate(CheckState::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// each side waits for the other one, which only works if they run concurrently
+    #[test]
+    fn par_or_works01() {
+        let (tx_a, rx_a) = mpsc::channel::<()>();
+        let (tx_b, rx_b) = mpsc::channel::<()>();
+        let (tx_a, rx_b) = (Mutex::new(tx_a), Mutex::new(rx_b));
+        let (tx_b, rx_a) = (Mutex::new(tx_b), Mutex::new(rx_a));
+
+        let wait_for_b = |_: &str| {
+            tx_a.lock().unwrap().send(()).unwrap();
+            rx_b.lock().unwrap()
+                .recv_timeout(Duration::from_secs(5))
+                .map_err(|_| ValidateErr::NotConcurrent)
+        };
+        let wait_for_a = |_: &str| {
+            tx_b.lock().unwrap().send(()).unwrap();
+            rx_a.lock().unwrap()
+                .recv_timeout(Duration::from_secs(5))
+                .map_err(|_| ValidateErr::NotConcurrent)
+        };
+
+        let checker =
+            check_ref::<str, Unchecked, Checked, _, _>(wait_for_b)
+            .par_or::<_, VecCombine<ValidateErr>>(check_ref::<str, Unchecked, Checked, _, _>(wait_for_a));
+
+        let r = checker.check(CheckState::new("hello"));
+        assert_eq!(r.to_result().err(), None);
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests_parse {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, check_noref, check_ref,
+            parse::{Parse, ParseOutcome, ParseValidator, parse_from_str, parse_with}
+        },
+        cmberr::VecCombine,
+        state::{Checked, Unchecked}
+    };
+    use std::num::ParseIntError;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        Empty,
+        NotNumber,
+        TooSmall,
+        Odd,
+    }
+
+    fn check_not_empty(data: CheckState<&str, Unchecked>)
+        -> CheckOutcome<&str, Unchecked, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if !value.is_empty() {
+                Ok(())
+            } else {
+                Err(ValidateErr::Empty)
+            }
+        })
+        .check(data)
+    }
+
+    fn parse_port(s: &str) -> Result<u16, ValidateErr> {
+        s.parse::<u16>().map_err(|_| ValidateErr::NotNumber)
+    }
+
+    fn check_not_well_known(data: CheckState<u16, Unchecked>)
+        -> CheckOutcome<u16, Unchecked, ValidateErr>
+    {
+        check_noref(|value: &u16| {
+            if 1024 <= *value {
+                Ok(())
+            } else {
+                Err(ValidateErr::TooSmall)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_even(data: CheckState<u16, Unchecked>)
+        -> CheckOutcome<u16, Checked, ValidateErr>
+    {
+        check_noref(|value: &u16| {
+            if value.is_multiple_of(2) {
+                Ok(())
+            } else {
+                Err(ValidateErr::Odd)
+            }
+        })
+        .check(data)
+    }
+
+    /// and_parse followed by checks on the parsed value
+    #[test]
+    fn parse_works00() {
+        let check_list = [
+            ("8080",  Ok(8080)),
+            ("",      Err(("", vec![ValidateErr::Empty]))),
+            ("http",  Err(("http", vec![ValidateErr::NotNumber]))),
+            ("80",    Err(("80", vec![ValidateErr::TooSmall]))),
+            ("8081",  Err(("8081", vec![ValidateErr::Odd]))),
+            ("81",    Err(("81", vec![ValidateErr::TooSmall, ValidateErr::Odd]))),
+        ];
+
+        for (s, ans) in check_list {
+            let checker = check_not_empty
+                .and_parse::<_, VecCombine<ValidateErr>>(parse_with(parse_port))
+                .and::<_, VecCombine<ValidateErr>>(
+                    check_not_well_known.or::<_, VecCombine<ValidateErr>>(check_even)
+                );
+
+            let r = checker.parse(CheckState::new(s));
+            assert_eq!(r.to_result_with_data(), ans);
+        }
+    }
+
+    /// parse_from_str keeps the error type of FromStr
+    #[test]
+    fn parse_works01() {
+        let check_list = [
+            ("42",  Some(42)),
+            ("-1",  None),
+            ("4x",  None),
+        ];
+
+        for (s, ans) in check_list {
+            let r = parse_from_str::<u8, Unchecked, Checked>()
+                .parse(CheckState::new(s));
+            let r: Result<u8, ParseIntError> = r.to_result();
+            assert_eq!(r.ok(), ans);
+        }
+    }
+
+    /// the parsed value keeps its state
+    #[test]
+    fn parse_works02() {
+        let checker = parse_with::<_, Unchecked, Unchecked, _, _, _>(parse_port)
+            .and::<_, VecCombine<ValidateErr>>(check_even);
+
+        let v = checker.parse(CheckState::new("8080")).into_validated().unwrap();
+        let port: u16 = *v;
+        assert_eq!(port, 8080);
+    }
+
+    /// or accumulates the errors of the checks on the parsed value
+    #[test]
+    fn parse_works03() {
+        let check_list = [
+            ("8080",  Ok(8080)),
+            ("http",  Err(("http", vec![ValidateErr::NotNumber]))),
+            ("80",    Err(("80", vec![ValidateErr::TooSmall]))),
+            ("8081",  Err(("8081", vec![ValidateErr::Odd]))),
+            ("81",    Err(("81", vec![ValidateErr::TooSmall, ValidateErr::Odd]))),
+        ];
+
+        for (s, ans) in check_list {
+            let checker = parse_with::<_, Unchecked, Unchecked, _, _, _>(parse_port)
+                .or::<_, VecCombine<ValidateErr>>(check_not_well_known)
+                .or::<_, VecCombine<ValidateErr>>(check_even);
+
+            let r = checker.parse(CheckState::new(s));
+            assert_eq!(r.to_result_with_data(), ans);
+        }
+
+        // a failed check on the parsed value keeps the parsed value
+        let checker = parse_with::<_, Unchecked, Unchecked, _, _, _>(parse_port)
+            .or::<_, VecCombine<ValidateErr>>(check_not_well_known)
+            .or::<_, VecCombine<ValidateErr>>(check_even);
+        match checker.parse(CheckState::new("81")) {
+            ParseOutcome::Rejected{input, state, err:_} => {
+                assert_eq!(input, "81");
+                assert_eq!(state.value, 81);
+            }
+            _ => panic!("expected a rejected outcome"),
+        }
+    }
+
+    /// validate reuses one parse pipeline for several values
+    #[test]
+    fn parse_works04() {
+        let checker = check_not_empty
+            .and_parse::<_, VecCombine<ValidateErr>>(parse_with(parse_port))
+            .and::<_, VecCombine<ValidateErr>>(
+                check_not_well_known.or::<_, VecCombine<ValidateErr>>(check_even)
+            );
+
+        let check_list = [
+            ("8080",  Ok(8080)),
+            ("",      Err(("", vec![ValidateErr::Empty]))),
+            ("81",    Err(("81", vec![ValidateErr::TooSmall, ValidateErr::Odd]))),
+        ];
+
+        for (s, ans) in check_list {
+            let r = checker.validate(CheckState::new(s));
+            assert_eq!(r.to_result_with_data(), ans);
+        }
+
+        let from_str = parse_from_str::<u16, Unchecked, Checked>();
+        assert_eq!(from_str.validate(CheckState::new("1")).to_result().ok(), Some(1));
+        assert!(!from_str.validate(CheckState::new("x")).is_passed());
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests_map_err {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, Validator, check_ref
+        },
+        cmberr::{
+            DefaultCombine, VecCombine
+        }
+    };
+
+    struct CheckStatus;
+
+    #[derive(Debug, PartialEq)]
+    struct StartsWithHelloErr;
+
+    #[derive(Debug, PartialEq)]
+    struct EndsWithWorldErr(usize);
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        StartsWithHello,
+        EndsWithWorld(usize),
+        TooShort,
+    }
+
+    impl From<StartsWithHelloErr> for ValidateErr {
+        fn from(_: StartsWithHelloErr) -> Self {
+            ValidateErr::StartsWithHello
+        }
+    }
+
+    impl From<EndsWithWorldErr> for ValidateErr {
+        fn from(e: EndsWithWorldErr) -> Self {
+            ValidateErr::EndsWithWorld(e.0)
+        }
+    }
+
+    fn check_starts_with_hello(data: &str) -> Result<(), StartsWithHelloErr> {
+        if data.starts_with("hello") { Ok(()) } else { Err(StartsWithHelloErr) }
+    }
+
+    fn check_ends_with_world(data: &str) -> Result<(), EndsWithWorldErr> {
+        if data.ends_with("world") { Ok(()) } else { Err(EndsWithWorldErr(data.len())) }
+    }
+
+    fn check_min6(data: CheckState<&str, CheckStatus>)
+        -> CheckOutcome<&str, CheckStatus, &'static str>
+    {
+        check_ref(|value: &str| {
+            if 6 <= value.len() {
+                Ok(())
+            } else {
+                Err("too short")
+            }
+        })
+        .check(data)
+    }
+
+    /// err_into unifies the error types before VecCombine
+    #[test]
+    fn map_err_works00() {
+        let check_list = [
+            ("hello world", None),
+            ("hello",       Some(vec![ValidateErr::EndsWithWorld(5), ValidateErr::TooShort])),
+            ("world",       Some(vec![ValidateErr::StartsWithHello, ValidateErr::TooShort])),
+            ("abc",         Some(vec![ValidateErr::StartsWithHello, ValidateErr::EndsWithWorld(3), ValidateErr::TooShort])),
+        ];
+
+        for (s, ans) in check_list {
+            let checker = check_ref::<str, CheckStatus, CheckStatus, _, _>(check_starts_with_hello)
+                .err_into::<ValidateErr>()
+                .or::<_, VecCombine<ValidateErr>>(
+                    check_ref::<str, CheckStatus, CheckStatus, _, _>(check_ends_with_world)
+                        .err_into::<ValidateErr>()
+                )
+                .or::<_, VecCombine<ValidateErr>>(
+                    check_min6.map_err(|_| ValidateErr::TooShort)
+                );
+
+            let r = checker.check(CheckState::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// map_err on a reusable validator with DefaultCombine
+    #[test]
+    fn map_err_works01() {
+        let checker = check_ref::<str, CheckStatus, CheckStatus, _, _>(check_starts_with_hello)
+            .map_err(|_| "not hello")
+            .and::<_, DefaultCombine<&str>>(check_min6);
+
+        let check_list = [
+            ("hello world", None),
+            ("hello",       Some("too short")),
+            ("abc",         Some("not hello")),
+        ];
+
+        for (s, ans) in check_list {
+            let r = checker.validate(CheckState::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests_outcome {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, check_ref
+        },
+        state::Unverified
+    };
+
+    struct Checked;
+    struct Unchecked;
+
+    #[derive(Debug, PartialEq, Clone)]
+    enum ValidateErr {
+        CheckMin6Err,
+        CheckStartsWithHelloErr,
+    }
+
+    fn check_min6(data: CheckState<&str, Unchecked>)
+        -> CheckOutcome<&str, Checked, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if 6 <= value.len() {
+                Ok(())
+            } else {
+                Err(ValidateErr::CheckMin6Err)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_starts_with_hello(data: CheckState<&str, Checked>)
+        -> CheckOutcome<&str, Checked, ValidateErr>
+    {
+        check_ref(|value: &str| {
+            if value.starts_with("hello") {
+                Ok(())
+            } else {
+                Err(ValidateErr::CheckStartsWithHelloErr)
+            }
+        })
+        .check(data)
+    }
+
+    /// outcomes compare with assert_eq!
+    #[test]
+    fn outcome_works00() {
+        let check_list = [
+            ("hello world", "Passed(\"hello world\")"),
+            ("hello",       "Failed { value: \"hello\", err: CheckMin6Err }"),
+            ("abcdefg",     "Failed { value: \"abcdefg\", err: CheckStartsWithHelloErr }"),
+        ];
+
+        for (s, ans) in check_list {
+            let r = check_min6(CheckState::new(s)).and_then(check_starts_with_hello);
+            assert_eq!(r.clone(), check_min6(CheckState::new(s)).and_then(check_starts_with_hello));
+            assert_ne!(r.clone(), check_min6(CheckState::new("hello abc")).and_then(check_starts_with_hello));
+            assert_eq!(format!("{:?}", r), ans);
+        }
+    }
+
+    /// accessors
+    #[test]
+    fn outcome_works01() {
+        let r = check_min6.check(CheckState::new("abc"));
+        assert!(r.is_failed());
+        assert!(!r.is_passed());
+        assert_eq!(*r.value(), "abc");
+        assert_eq!(r.clone().err(), Some(ValidateErr::CheckMin6Err));
+
+        let mut seen = None;
+        let n = r
+            .inspect_err(|e| seen = Some(e.clone()))
+            .map_value(str::len)
+            .map_err(|_| "too short")
+            .unwrap_or_else(|len, _| len * 10);
+        assert_eq!(n, 30);
+        assert_eq!(seen, Some(ValidateErr::CheckMin6Err));
+
+        let r = check_min6.check(CheckState::new("abcdefg"));
+        assert!(r.is_passed());
+        let mapped: CheckOutcome<usize, Unverified, ValidateErr> = r.clone().map_value(str::len);
+        assert_eq!(mapped, Ok(7).into());
+        assert_eq!(r.err(), None);
+
+        let r: CheckOutcome<&str, Unverified, ValidateErr> = Err(("abc", ValidateErr::CheckMin6Err)).into();
+        assert_eq!(r.to_result_with_data(), Err(("abc", ValidateErr::CheckMin6Err)));
+        let r: CheckOutcome<&str, Unverified, ValidateErr> = Ok("abc").into();
+        assert!(r.is_passed());
+        assert_eq!(
+            format!("{:?}", check_min6(CheckState::new("abc"))),
+            "Failed { value: \"abc\", err: CheckMin6Err }"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests_field {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator, check_ref,
+            field::{FieldError, FieldPath, PathParseError, PathSegment, by_path, field, nested}
+        },
+        cmberr::VecCombine
+    };
+
+    struct CheckStatus;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        Empty,
+        NotDigits,
+    }
+
+    struct Address {
+        city: String,
+        zip: String,
+    }
+
+    struct User {
+        name: String,
+        address: Address,
+    }
+
+    fn check_not_empty(data: &str) -> Result<(), ValidateErr> {
+        if !data.is_empty() { Ok(()) } else { Err(ValidateErr::Empty) }
+    }
+
+    fn check_digits(data: &str) -> Result<(), ValidateErr> {
+        if data.chars().all(|c| c.is_ascii_digit()) { Ok(()) } else { Err(ValidateErr::NotDigits) }
+    }
+
+    fn user(name: &str, city: &str, zip: &str) -> User {
+        User {
+            name: name.to_string(),
+            address: Address { city: city.to_string(), zip: zip.to_string() }
+        }
+    }
+
+    type E = FieldError<ValidateErr>;
+
+    /// paths compose through nested
+    #[test]
+    fn field_works00() {
+        let address = field(
+                "city",
+                |a: &Address| a.city.as_str(),
+                check_ref::<str, CheckStatus, CheckStatus, _, _>(check_not_empty)
+            )
+            .or::<_, VecCombine<E>>(field(
+                "zip",
+                |a: &Address| a.zip.as_str(),
+                check_ref::<str, CheckStatus, CheckStatus, _, _>(check_digits)
+            ));
+        let checker = field(
+                "name",
+                |u: &User| u.name.as_str(),
+                check_ref::<str, CheckStatus, CheckStatus, _, _>(check_not_empty)
+            )
+            .or::<_, VecCombine<E>>(nested("address", |u: &User| &u.address, address));
+
+        let check_list = [
+            (user("alice", "tokyo", "100"), None),
+            (user("", "tokyo", "100"), Some(vec![
+                FieldError::new("name", ValidateErr::Empty),
+            ])),
+            (user("", "", "1a"), Some(vec![
+                FieldError::new("name", ValidateErr::Empty),
+                FieldError::new("address.city", ValidateErr::Empty),
+                FieldError::new("address.zip", ValidateErr::NotDigits),
+            ])),
+        ];
+
+        for (u, ans) in &check_list {
+            let r = checker.validate(CheckState::new(u));
+            assert_eq!(r.to_result().err().as_ref(), ans.as_ref());
+        }
+
+        let u = user("", "", "1a");
+        let errs = checker.validate(CheckState::new(&u)).to_result().err().unwrap();
+        let map = by_path(errs);
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["address.city", "address.zip", "name"]);
+        assert_eq!(map["address.zip"], vec![ValidateErr::NotDigits]);
+    }
+
+    /// path parsing and display
+    #[test]
+    fn field_works01() {
+        let p = FieldPath::parse("orders[2].items[0].sku");
+        assert_eq!(p.segments(), &[
+            PathSegment::Field("orders".to_string()),
+            PathSegment::Index(2),
+            PathSegment::Field("items".to_string()),
+            PathSegment::Index(0),
+            PathSegment::Field("sku".to_string()),
+        ]);
+        assert_eq!(p.to_string(), "orders[2].items[0].sku");
+
+        let mut p = FieldPath::from(3);
+        p.prepend(&FieldPath::parse("tags"));
+        assert_eq!(p.to_string(), "tags[3]");
+        assert!(FieldPath::parse("").is_empty());
+    }
+
+    /// field on a single check, consumed by check
+    #[test]
+    fn field_works02() {
+        let u = user("alice", "tokyo", "x");
+        let r = field(
+                "address.zip",
+                |u: &User| u.address.zip.as_str(),
+                check_ref::<str, CheckStatus, CheckStatus, _, _>(check_digits)
+            )
+            .check(CheckState::new(&u));
+        let err = r.err().unwrap();
+        assert_eq!(err, FieldError::new("address.zip", ValidateErr::NotDigits));
+        assert_eq!(format!("{}: {:?}", err.path, err.err), "address.zip: NotDigits");
+    }
+
+    /// malformed and non-ASCII paths
+    #[test]
+    fn field_works03() {
+        let check_list = [
+            ("a[1]é]",    Err(PathParseError { offset: 4 })),
+            ("a[1]x[2]",  Err(PathParseError { offset: 4 })),
+            ("a[1",       Err(PathParseError { offset: 1 })),
+            ("a[]",       Err(PathParseError { offset: 1 })),
+            ("a]",        Err(PathParseError { offset: 1 })),
+            ("b.a[[1]]",  Err(PathParseError { offset: 3 })),
+            ("a..b",      Err(PathParseError { offset: 2 })),
+            (".a",        Err(PathParseError { offset: 0 })),
+            ("a.",        Err(PathParseError { offset: 2 })),
+            ("名前[1].é", Ok("名前[1].é")),
+            ("a[key][0]", Ok("a.key[0]")),
+        ];
+
+        for (s, ans) in check_list {
+            let r = FieldPath::try_parse(s).map(|p| p.to_string());
+            assert_eq!(r.as_deref().map_err(|e| *e), ans, "{}", s);
+        }
+
+        // parse never keeps a malformed path
+        for s in ["a[1]é]", "items[0", "a..b"] {
+            let r = std::panic::catch_unwind(|| FieldPath::from(s));
+            assert!(r.is_err(), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests_each {
+    use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator, check_ref,
+            each::{IterElements, each, each_fail_fast},
+            field::{FieldError, nested}
+        },
+        cmberr::{DefaultCombine, VecCombine}
+    };
+
+    struct CheckStatus;
+
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    enum ValidateErr {
+        Negative,
+    }
+
+    fn check_positive(data: &i32) -> Result<(), ValidateErr> {
+        if 0 <= *data { Ok(()) } else { Err(ValidateErr::Negative) }
+    }
+
+    type E = FieldError<ValidateErr>;
+
+    /// collect all failed elements
+    #[test]
+    fn each_works00() {
+        let checker = each::<_, VecCombine<E>>(
+            check_ref::<i32, CheckStatus, CheckStatus, _, _>(check_positive)
+        );
+
+        let check_list = [
+            (vec![1, 2, 3],     None),
+            (vec![],            None),
+            (vec![-1, 2, -3],   Some(vec![
+                FieldError::new(0, ValidateErr::Negative),
+                FieldError::new(2, ValidateErr::Negative),
+            ])),
+        ];
+
+        for (v, ans) in &check_list {
+            let r = checker.validate(CheckState::new(v.as_slice()));
+            assert_eq!(r.to_result().err().as_ref(), ans.as_ref());
+        }
+    }
+
+    /// stop at the first failed element
+    #[test]
+    fn each_works01() {
+        let check_list = [
+            (vec![1, 2, 3],     None),
+            (vec![1, -2, -3],   Some(vec![FieldError::new(1, ValidateErr::Negative)])),
+        ];
+
+        for (v, ans) in check_list {
+            let checker = each_fail_fast::<_, VecCombine<E>>(
+                check_ref::<i32, CheckStatus, CheckStatus, _, _>(check_positive)
+            );
+            let r = checker.check(CheckState::new(&v));
+            assert_eq!(r.to_result().err(), ans);
+        }
+
+        let v = vec![-1, -2];
+        let r = each::<_, DefaultCombine<E>>(
+                check_ref::<i32, CheckStatus, CheckStatus, _, _>(check_positive)
+            )
+            .check(CheckState::new(&v));
+        assert_eq!(r.err(), Some(FieldError::new(1, ValidateErr::Negative)));
+    }
+
+    struct Order {
+        amounts: Vec<i32>,
+    }
+
+    /// indices compose with field paths
+    #[test]
+    fn each_works02() {
+        let order = Order { amounts: vec![3, -1, 4] };
+        let r = nested(
+                "amounts",
+                |o: &Order| &o.amounts,
+                each::<_, VecCombine<E>>(
+                    check_ref::<i32, CheckStatus, CheckStatus, _, _>(check_positive)
+                )
+            )
+            .check(CheckState::new(&order));
+        let errs = r.err().unwrap();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].path.to_string(), "amounts[1]");
+    }
+
+    /// owned collections are checked by reference
+    #[test]
+    fn each_works03() {
+        let checker = each::<_, VecCombine<E>>(
+            check_ref::<i32, CheckStatus, CheckStatus, _, _>(check_positive)
+        );
+        let r = checker.validate(CheckState::new(vec![1, -1, -2]));
+        assert_eq!(*r.value(), vec![1, -1, -2]);
+        assert_eq!(r.err(), Some(vec![
+            FieldError::new(1, ValidateErr::Negative),
+            FieldError::new(2, ValidateErr::Negative),
+        ]));
+
+        let r = checker.validate(CheckState::new([1, 2, 3]));
+        assert!(r.is_passed());
+
+        // DefaultCombine keeps the last failed element
+        let r = each::<_, DefaultCombine<E>>(
+                check_ref::<i32, CheckStatus, CheckStatus, _, _>(check_positive)
+            )
+            .check(CheckState::new(vec![1, -1, -2]));
+        assert_eq!(r.err(), Some(FieldError::new(2, ValidateErr::Negative)));
+    }
+
+    /// non-contiguous collections and iterators
+    #[test]
+    fn each_works04() {
+        let checker = each::<_, VecCombine<E>>(
+            check_ref::<i32, CheckStatus, CheckStatus, _, _>(check_positive)
+        );
+
+        let mut q = VecDeque::from([2, -3]);
+        q.push_front(-1);
+        let r = checker.validate(CheckState::new(&q));
+        assert_eq!(r.err(), Some(vec![
+            FieldError::new(0, ValidateErr::Negative),
+            FieldError::new(2, ValidateErr::Negative),
+        ]));
+
+        let set = BTreeSet::from([3, -5, 1]);
+        let r = checker.validate(CheckState::new(set));
+        assert_eq!(r.err(), Some(vec![FieldError::new(0, ValidateErr::Negative)]));
+
+        let map = BTreeMap::from([("a", 1), ("b", -1)]);
+        let r = checker.validate(CheckState::new(IterElements::new(map.values())));
+        assert_eq!(r.err(), Some(vec![FieldError::new(1, ValidateErr::Negative)]));
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests_option {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, Validator, check_noref, check_ref,
+            option::{optional, required}
+        },
+        cmberr::VecCombine,
+        state::{Checked, Unchecked}
+    };
+
+    #[derive(Debug, PartialEq, Clone)]
+    enum ValidateErr {
+        Missing,
+        TooShort,
+    }
+
+    fn check_min3(data: &str) -> Result<(), ValidateErr> {
+        if 3 <= data.len() { Ok(()) } else { Err(ValidateErr::TooShort) }
+    }
+
+    // only accepts values whose option was considered
+    fn after_check(data: CheckState<Option<&str>, Checked>)
+        -> CheckOutcome<Option<&str>, Checked, ValidateErr>
+    {
+        check_noref(|_: &Option<&str>| Ok(())).check(data)
+    }
+
+    /// optional passes on None
+    #[test]
+    fn option_works00() {
+        let checker = optional(check_ref::<str, Unchecked, Checked, _, _>(check_min3))
+            .and::<_, VecCombine<ValidateErr>>(after_check);
+
+        let check_list = [
+            (Some("alice"), None),
+            (None,          None),
+            (Some("al"),    Some(vec![ValidateErr::TooShort])),
+        ];
+
+        for (s, ans) in check_list {
+            let r = checker.validate(CheckState::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// required fails on None
+    #[test]
+    fn option_works01() {
+        let checker = required(
+            ValidateErr::Missing,
+            check_ref::<str, Unchecked, Checked, _, _>(check_min3)
+        );
+
+        let check_list = [
+            (Some("alice"), None),
+            (None,          Some(ValidateErr::Missing)),
+            (Some("al"),    Some(ValidateErr::TooShort)),
+        ];
+
+        for (s, ans) in check_list {
+            let r = checker.validate(CheckState::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// owned values are handed back on failure
+    #[test]
+    fn option_works02() {
+        let r = required(
+                ValidateErr::Missing,
+                check_noref::<String, Unchecked, Checked, _, _>(|s: &String| check_min3(s))
+            )
+            .check(CheckState::new(Some("al".to_string())));
+        assert_eq!(r.to_result_with_data(), Err((Some("al".to_string()), ValidateErr::TooShort)));
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests_cond {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator, check_ref,
+            cond::{if_else, unless, when}
+        },
+        cmberr::VecCombine
+    };
+
+    struct CheckStatus;
+
+    #[derive(Debug, PartialEq)]
+    enum ValidateErr {
+        Zip5,
+        ZipAny,
+        Empty,
+    }
+
+    struct Address {
+        country: &'static str,
+        zip: &'static str,
+    }
+
+    fn check_zip5(a: &Address) -> Result<(), ValidateErr> {
+        if a.zip.len() == 5 && a.zip.chars().all(|c| c.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err(ValidateErr::Zip5)
+        }
+    }
+
+    fn check_zip_any(a: &Address) -> Result<(), ValidateErr> {
+        if a.zip.len() <= 10 { Ok(()) } else { Err(ValidateErr::ZipAny) }
+    }
+
+    fn check_not_empty(a: &Address) -> Result<(), ValidateErr> {
+        if !a.zip.is_empty() { Ok(()) } else { Err(ValidateErr::Empty) }
+    }
+
+    fn is_us(a: &&Address) -> bool {
+        a.country == "US"
+    }
+
+    /// when and unless composed with or
+    #[test]
+    fn cond_works00() {
+        let checker = when(is_us, check_ref::<Address, CheckStatus, CheckStatus, _, _>(check_zip5))
+            .or::<_, VecCombine<ValidateErr>>(
+                unless(is_us, check_ref::<Address, CheckStatus, CheckStatus, _, _>(check_not_empty))
+            );
+
+        let check_list = [
+            (Address { country: "US", zip: "12345" },  None),
+            (Address { country: "US", zip: "1234" },   Some(vec![ValidateErr::Zip5])),
+            (Address { country: "US", zip: "" },       Some(vec![ValidateErr::Zip5])),
+            (Address { country: "JP", zip: "1234" },   None),
+            (Address { country: "JP", zip: "" },       Some(vec![ValidateErr::Empty])),
+        ];
+
+        for (a, ans) in &check_list {
+            let r = checker.validate(CheckState::new(a));
+            assert_eq!(r.to_result().err().as_ref(), ans.as_ref());
+        }
+    }
+
+    /// if_else picks one branch
+    #[test]
+    fn cond_works01() {
+        let check_list = [
+            (Address { country: "US", zip: "12345" },        None),
+            (Address { country: "US", zip: "1234567" },      Some(ValidateErr::Zip5)),
+            (Address { country: "GB", zip: "SW1A 1AA" },     None),
+            (Address { country: "GB", zip: "SW1A 1AA 1AA" }, Some(ValidateErr::ZipAny)),
+        ];
+
+        for (a, ans) in check_list {
+            let checker = if_else(
+                is_us,
+                check_ref::<Address, CheckStatus, CheckStatus, _, _>(check_zip5),
+                check_ref::<Address, CheckStatus, CheckStatus, _, _>(check_zip_any)
+            );
+            let r = checker.check(CheckState::new(&a));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+}
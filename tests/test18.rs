@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests_cross {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, Validated, Validator, check_noref,
+            field::{CrossFieldError, FieldError, FieldPath, by_path, cross}
+        },
+        state::{Has, Mark, Set},
+        States
+    };
+
+    struct Password;
+    struct Confirm;
+    struct Range;
+
+    #[derive(Debug, PartialEq, Clone)]
+    enum ValidateErr {
+        Mismatch,
+        MinAboveMax,
+    }
+
+    struct Form {
+        password: &'static str,
+        confirm: &'static str,
+        min: u32,
+        max: u32,
+    }
+
+    type Passwords<'a> = (&'a str, &'a str);
+
+    fn check_same<S>(data: CheckState<Passwords<'_>, S>)
+        -> CheckOutcome<Passwords<'_>, Mark<Mark<S, Password>, Confirm>, ValidateErr>
+    where
+        S: Set<Password>,
+        Mark<S, Password>: Set<Confirm>,
+    {
+        check_noref(|value: &Passwords<'_>| {
+            if value.0 == value.1 {
+                Ok(())
+            } else {
+                Err(ValidateErr::Mismatch)
+            }
+        })
+        .check(data)
+    }
+
+    fn check_range<S: Set<Range>>(data: CheckState<(u32, u32), S>)
+        -> CheckOutcome<(u32, u32), Mark<S, Range>, ValidateErr>
+    {
+        check_noref(|value: &(u32, u32)| {
+            if value.0 <= value.1 {
+                Ok(())
+            } else {
+                Err(ValidateErr::MinAboveMax)
+            }
+        })
+        .check(data)
+    }
+
+    // only accepts forms whose password fields are both checked
+    fn save<S, I, J>(_: Validated<&Form, S>)
+    where
+        S: Has<Password, I> + Has<Confirm, J>
+    {}
+
+    fn form(password: &'static str, confirm: &'static str) -> Form {
+        Form { password, confirm, min: 1, max: 2 }
+    }
+
+    /// the error names every involved field
+    #[test]
+    fn cross_works00() {
+        let checker = cross(
+            ["confirm", "password"],
+            |f: &Form| (f.password, f.confirm),
+            check_same
+        );
+
+        let check_list = [
+            (form("secret", "secret"), None),
+            (form("secret", "secrat"), Some(CrossFieldError {
+                paths: vec![FieldPath::parse("confirm"), FieldPath::parse("password")],
+                err: ValidateErr::Mismatch
+            })),
+        ];
+
+        for (f, ans) in &check_list {
+            let r = checker.validate(CheckState::<_, States![]>::new(f));
+            match r {
+                CheckOutcome::Passed(v) => {
+                    assert!(ans.is_none());
+                    save(v);
+                }
+                CheckOutcome::Failed{state:_, err} => {
+                    assert_eq!(Some(&err), ans.as_ref());
+                    assert_eq!(err.primary().unwrap().to_string(), "confirm");
+                }
+            }
+        }
+    }
+
+    /// cross errors flatten into per-field errors
+    #[test]
+    fn cross_works01() {
+        let f = Form { password: "a", confirm: "a", min: 5, max: 3 };
+        let err = cross(["min", "max"], |f: &Form| (f.min, f.max), check_range)
+            .check(CheckState::<_, States![]>::new(&f))
+            .err()
+            .unwrap();
+        assert_eq!(err.paths.len(), 2);
+
+        let errs = err.into_field_errors();
+        assert_eq!(errs, vec![
+            FieldError::new("min", ValidateErr::MinAboveMax),
+            FieldError::new("max", ValidateErr::MinAboveMax),
+        ]);
+        let map = by_path(errs);
+        assert_eq!(map["max"], vec![ValidateErr::MinAboveMax]);
+    }
+}
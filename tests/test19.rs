@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests_string_rules {
+    use error_combinator::{
+        check::{
+            Check, CheckOutcome, CheckState, Validated, Validator,
+            rules::string::{
+                Length, alphanumeric, ascii_only, contains, ends_with, len_between,
+                lowercase, matches_charset, max_len, min_len, no_whitespace,
+                starts_with, uppercase
+            }
+        },
+        cmberr::VecCombine,
+        state::Has,
+        States
+    };
+
+    #[derive(Debug, PartialEq, Clone)]
+    enum ValidateErr {
+        StartsWithHello,
+        Min6,
+        EndsWithWorld,
+        IncludesAbc,
+    }
+
+    struct Hello;
+    struct Min6;
+
+    /// the rules of test00, built from the library
+    #[test]
+    fn string_rules_works00() {
+        let check_list = [
+            ("hello abc world", None),
+            ("hello--",         Some(vec![ValidateErr::EndsWithWorld, ValidateErr::IncludesAbc])),
+            ("abc----",         Some(vec![ValidateErr::StartsWithHello, ValidateErr::EndsWithWorld])),
+            ("a",               Some(vec![
+                ValidateErr::StartsWithHello, ValidateErr::Min6,
+                ValidateErr::EndsWithWorld, ValidateErr::IncludesAbc,
+            ])),
+        ];
+
+        let checker = starts_with("hello", ValidateErr::StartsWithHello)
+            .or::<_, VecCombine<ValidateErr>>(min_len(6, ValidateErr::Min6))
+            .or::<_, VecCombine<ValidateErr>>(ends_with("world", ValidateErr::EndsWithWorld))
+            .or::<_, VecCombine<ValidateErr>>(contains("abc", ValidateErr::IncludesAbc));
+
+        for (s, ans) in check_list {
+            let r = checker.validate(CheckState::<_, ()>::new(s));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    // only accepts strings whose prefix and length were checked
+    fn greet<S, I, J>(_: Validated<&str, S>)
+    where
+        S: Has<Hello, I> + Has<Min6, J>
+    {}
+
+    /// marked rules record their tag
+    #[test]
+    fn string_rules_works01() {
+        let checker = starts_with("hello", ValidateErr::StartsWithHello).mark::<Hello>()
+            .and::<_, VecCombine<ValidateErr>>(min_len(6, ValidateErr::Min6).mark::<Min6>());
+
+        match checker.check(CheckState::<_, States![]>::new("hello world")) {
+            CheckOutcome::Passed(v) => greet(v),
+            CheckOutcome::Failed{..} => panic!("Failed!"),
+        }
+    }
+
+    /// length units and char rules
+    #[test]
+    fn string_rules_works02() {
+        let s = "héllo";
+        assert!(min_len(5, ()).validate(CheckState::<_, ()>::new(s)).is_passed());
+        assert!(max_len(5, ()).validate(CheckState::<_, ()>::new(s)).is_passed());
+        assert!(max_len(Length::Bytes(5), ()).validate(CheckState::<_, ()>::new(s)).is_failed());
+        assert!(len_between(Length::Bytes(6), 5, ()).validate(CheckState::<_, ()>::new(s)).is_passed());
+
+        let check_list = [
+            ("abc_123", [true, false, true, true, false, true]),
+            ("ABC",     [true, true, true, false, true, false]),
+            ("a b",     [true, false, false, true, false, false]),
+            ("日本",     [false, true, true, true, true, false]),
+        ];
+
+        for (s, ans) in check_list {
+            let r = [
+                ascii_only(()).check(CheckState::<_, ()>::new(s)).is_passed(),
+                alphanumeric(()).check(CheckState::<_, ()>::new(s)).is_passed(),
+                no_whitespace(()).check(CheckState::<_, ()>::new(s)).is_passed(),
+                lowercase(()).check(CheckState::<_, ()>::new(s)).is_passed(),
+                uppercase(()).check(CheckState::<_, ()>::new(s)).is_passed(),
+                matches_charset(['a'..='z', '0'..='9', '_'..='_'], ())
+                    .check(CheckState::<_, ()>::new(s)).is_passed(),
+            ];
+            assert_eq!(r, ans, "{}", s);
+        }
+
+        assert!(matches_charset("0123456789abcdef", ()).check(CheckState::<_, ()>::new("c0ffee")).is_passed());
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn string_rules_works03() {
+        let family = "👨‍👩‍👧";
+        assert!(max_len(Length::Graphemes(1), ()).check(CheckState::<_, ()>::new(family)).is_passed());
+        assert!(max_len(1, ()).check(CheckState::<_, ()>::new(family)).is_failed());
+    }
+}
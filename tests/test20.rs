@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests_numeric_rules {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator, check_noref,
+            rules::numeric::{
+                NumericError, between, decimal, finite, multiple_of, non_negative,
+                positive, range
+            }
+        },
+        cmberr::VecCombine
+    };
+    use std::ops::Bound;
+
+    struct CheckStatus;
+
+    /// bounds are carried by the error
+    #[test]
+    fn numeric_rules_works00() {
+        let check_list = [
+            (8080,  None),
+            (0,     Some("must be between 1 and 65535")),
+            (70000, Some("must be between 1 and 65535")),
+        ];
+
+        let port = between::<1, 65535, u32, CheckStatus>();
+        for (v, ans) in check_list {
+            let r = port.validate(CheckState::new(v));
+            assert_eq!(r.to_result().err().map(|e| e.to_string()).as_deref(), ans);
+        }
+
+        let r = range(0.0..1.0).check(CheckState::<_, CheckStatus>::new(1.0));
+        assert_eq!(r.err(), Some(NumericError::OutOfRange{
+            min: Bound::Included(0.0),
+            max: Bound::Excluded(1.0)
+        }));
+        let r = range(18..).check(CheckState::<_, CheckStatus>::new(17));
+        assert_eq!(r.err().unwrap().to_string(), "must be at least 18");
+
+        // -5 is not a u8, so only the upper bound is checked
+        let r = between::<-5, 10, u8, CheckStatus>().check(CheckState::new(11));
+        assert_eq!(r.err().unwrap().to_string(), "must be at most 10");
+
+        // no u8 is in these ranges, so every value fails
+        for v in [0, 5, 255] {
+            let r = between::<300, 400, u8, CheckStatus>().check(CheckState::new(v));
+            assert_eq!(r.err(), Some(NumericError::EmptyRange{ min: 300, max: 400 }));
+            let r = between::<-10, -5, u8, CheckStatus>().check(CheckState::new(v));
+            assert_eq!(r.err(), Some(NumericError::EmptyRange{ min: -10, max: -5 }));
+        }
+        let r = between::<-10, 300, u8, CheckStatus>().check(CheckState::new(255));
+        assert!(r.is_passed());
+        let r = between::<300, 400, u8, CheckStatus>().check(CheckState::new(5));
+        assert_eq!(r.err().unwrap().to_string(), "must be between 300 and 400");
+    }
+
+    /// rules accumulate in a pipeline on a Copy value
+    #[test]
+    fn numeric_rules_works01() {
+        let check_list = [
+            (10,   None),
+            (-3,   Some(vec![NumericError::NotPositive, NumericError::NotMultipleOf{ divisor: 2 }])),
+            (0,    Some(vec![NumericError::NotPositive])),
+            (i32::MIN, Some(vec![NumericError::NotPositive])),
+            (100,  Some(vec![NumericError::OutOfRange{ min: Bound::Unbounded, max: Bound::Excluded(100) }])),
+        ];
+
+        let checker = check_noref::<i32, CheckStatus, CheckStatus, _, _>(|v: &i32| {
+                if *v < 100 {
+                    Ok(())
+                } else {
+                    Err(vec![NumericError::OutOfRange{ min: Bound::Unbounded, max: Bound::Excluded(100) }])
+                }
+            })
+            .and::<_, VecCombine<NumericError<i32>>>(
+                positive().or::<_, VecCombine<NumericError<i32>>>(multiple_of(2))
+            )
+            .and::<_, VecCombine<NumericError<i32>>>(non_negative());
+
+        for (v, ans) in check_list {
+            let r = checker.validate(CheckState::new(v));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    /// floats
+    #[test]
+    fn numeric_rules_works02() {
+        let check_list = [
+            (1.5,           [true, true, true, true]),
+            (-0.25,         [true, false, true, true]),
+            (f64::NAN,      [false, false, false, false]),
+            (f64::INFINITY, [false, true, false, false]),
+            (0.1,           [true, true, true, false]),
+            (123.456,       [true, true, false, false]),
+            (1000.5,        [true, true, false, true]),
+        ];
+
+        for (v, ans) in check_list {
+            let r = [
+                finite().check(CheckState::<_, ()>::new(v)).is_passed(),
+                non_negative().check(CheckState::<_, ()>::new(v)).is_passed(),
+                decimal(5, 2).check(CheckState::<_, ()>::new(v)).is_passed(),
+                multiple_of(0.25).check(CheckState::<_, ()>::new(v)).is_passed(),
+            ];
+            assert_eq!(r, ans, "{}", v);
+        }
+
+        assert!(decimal(5, 2).check(CheckState::<_, ()>::new(999_u32)).is_passed());
+        assert_eq!(
+            decimal(5, 2).check(CheckState::<_, ()>::new(1000_u32)).err().unwrap().to_string(),
+            "must have at most 5 digits, 2 of them after the decimal point"
+        );
+    }
+
+    /// float multiple_of tolerates rounding of the divisor
+    #[test]
+    fn numeric_rules_works03() {
+        let check_list = [
+            (0.3,       true),
+            (0.7,       true),
+            (-1.1,      true),
+            (12345.6,   true),
+            (0.0,       true),
+            (0.35,      false),
+            (0.1000001, false),
+            (f64::NAN,  false),
+            (f64::INFINITY, false),
+        ];
+
+        for (v, ans) in check_list {
+            let r = multiple_of(0.1).check(CheckState::<_, ()>::new(v)).is_passed();
+            assert_eq!(r, ans, "{}", v);
+        }
+
+        assert!(multiple_of(0.1_f32).check(CheckState::<_, ()>::new(0.3_f32)).is_passed());
+        assert!(multiple_of(0.0).check(CheckState::<_, ()>::new(0.0)).is_failed());
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests_collection_rules {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator,
+            rules::collection::{
+                CollectionError, KeysError, contains, max_items, min_items, no_extra_keys,
+                required_keys, sorted, unique, unique_by
+            }
+        },
+        cmberr::VecCombine
+    };
+    use std::collections::{BTreeMap, HashMap};
+
+    struct CheckStatus;
+
+    /// the Vec<i32> checks of examples/simple01.rs
+    #[test]
+    fn collection_rules_works00() {
+        let check_list = [
+            (vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9], None),
+            (vec![0, 1, 2],                      Some(vec![CollectionError::TooFewItems{ min: 10, len: 3 }])),
+            (vec![1, 1, 2, 3, 4, 5, 6, 7, 9, 8], Some(vec![
+                CollectionError::MissingItem,
+                CollectionError::Duplicate{ first: 0, index: 1 },
+                CollectionError::NotSorted{ index: 9 },
+            ])),
+            (vec![0; 11],                        Some(vec![
+                CollectionError::TooManyItems{ max: 10, len: 11 },
+                CollectionError::Duplicate{ first: 0, index: 1 },
+            ])),
+        ];
+
+        let checker = min_items(10)
+            .or::<_, VecCombine<CollectionError>>(max_items(10))
+            .or::<_, VecCombine<CollectionError>>(contains(0))
+            .or::<_, VecCombine<CollectionError>>(unique())
+            .or::<_, VecCombine<CollectionError>>(sorted());
+
+        for (v, ans) in check_list {
+            let r = checker.validate(CheckState::<_, CheckStatus>::new(v));
+            assert_eq!(r.to_result().err(), ans);
+        }
+    }
+
+    struct User {
+        id: u32,
+    }
+
+    /// slices and keys of the items
+    #[test]
+    fn collection_rules_works01() {
+        let users = [User { id: 1 }, User { id: 2 }, User { id: 1 }];
+        let r = unique_by(|u: &User| u.id).check(CheckState::<_, ()>::new(&users[..]));
+        assert_eq!(r.err(), Some(CollectionError::Duplicate{ first: 0, index: 2 }));
+        assert!(unique_by(|u: &User| u.id).check(CheckState::<_, ()>::new(&users[..2])).is_passed());
+
+        // the key is computed once per item
+        let calls = std::cell::Cell::new(0);
+        let key = |u: &User| { calls.set(calls.get() + 1); u.id };
+        assert!(unique_by(key).check(CheckState::<_, ()>::new(&users[..2])).is_passed());
+        assert_eq!(calls.get(), 2);
+
+        let r = min_items(4).check(CheckState::<_, ()>::new(&users));
+        assert_eq!(r.err().unwrap().to_string(), "must have at least 4 items, got 3");
+    }
+
+    /// map keys
+    #[test]
+    fn collection_rules_works02() {
+        let mut conf = HashMap::new();
+        conf.insert("host", "localhost");
+        conf.insert("debug", "true");
+
+        let r = required_keys(["host", "port"]).check(CheckState::<_, ()>::new(&conf));
+        assert_eq!(r.err(), Some(KeysError::Missing(vec!["port"])));
+
+        let r = no_extra_keys(["host", "port"]).check(CheckState::<_, ()>::new(&conf));
+        assert_eq!(r.err(), Some(KeysError::Extra(vec!["debug"])));
+
+        let conf: BTreeMap<String, u32> = [("a".to_string(), 1), ("b".to_string(), 2)].into();
+        let checker = required_keys(["a", "b"].map(String::from))
+            .or::<_, VecCombine<KeysError<String>>>(no_extra_keys(["a", "b", "c"].map(String::from)))
+            .and::<_, VecCombine<KeysError<String>>>(max_items(2).map_err(|_| KeysError::Extra(vec![])));
+        assert!(checker.validate(CheckState::<_, ()>::new(conf)).is_passed());
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests_net_rules {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator,
+            rules::net::{
+                GroupError, LabelError, NetError, OctetError, cidr, domain_label, hostname,
+                ip, ipv4, ipv6, mac, port, port_range
+            }
+        },
+        cmberr::VecCombine
+    };
+
+    fn run<C: Validator<&'static str, (), Error = NetError>>(checker: &C, s: &'static str) -> Option<NetError> {
+        checker.validate(CheckState::new(s)).err()
+    }
+
+    /// IPv4 octets are reported by index
+    #[test]
+    fn net_rules_works00() {
+        let check_list = [
+            ("192.168.0.1",  None),
+            ("0.0.0.0",      None),
+            ("192.168.0",    Some(NetError::Ipv4OctetCount(3))),
+            ("192.168.0.256", Some(NetError::Ipv4Octet{ index: 3, kind: OctetError::TooLarge })),
+            ("192.168.01.1", Some(NetError::Ipv4Octet{ index: 2, kind: OctetError::LeadingZero })),
+            ("192..0.1",     Some(NetError::Ipv4Octet{ index: 1, kind: OctetError::Empty })),
+            ("a.0.0.1",      Some(NetError::Ipv4Octet{ index: 0, kind: OctetError::NotDecimal })),
+            ("",             Some(NetError::Empty)),
+        ];
+
+        let checker = ipv4();
+        for (s, ans) in check_list {
+            assert_eq!(run(&checker, s), ans, "{}", s);
+        }
+        assert_eq!(
+            run(&checker, "1.2.3.999").unwrap().to_string(),
+            "IPv4 octet 4 is greater than 255"
+        );
+    }
+
+    /// IPv6 with compression, embedded IPv4 and zone IDs
+    #[test]
+    fn net_rules_works01() {
+        let check_list = [
+            ("2001:db8:0:0:0:0:2:1", None),
+            ("2001:db8::2:1",        None),
+            ("::",                   None),
+            ("::1",                  None),
+            ("fe80::",               None),
+            ("::ffff:192.0.2.1",     None),
+            ("fe80::1%eth0",         None),
+            ("fe80::1%",             Some(NetError::Ipv6ZoneId)),
+            ("1:2:3:4:5:6:7",        Some(NetError::Ipv6GroupCount(7))),
+            ("1:2:3:4::5:6:7:8",     Some(NetError::Ipv6GroupCount(8))),
+            ("1::2::3",              Some(NetError::Ipv6DoubleColon)),
+            ("1:::3",                Some(NetError::Ipv6DoubleColon)),
+            ("2001:db8::g:1",        Some(NetError::Ipv6Group{ index: 2, kind: GroupError::NotHex })),
+            ("2001:db8::12345",      Some(NetError::Ipv6Group{ index: 2, kind: GroupError::TooLong })),
+            (":1:2:3:4:5:6:7",       Some(NetError::Ipv6Group{ index: 0, kind: GroupError::Empty })),
+            ("::ffff:192.0.2.300",   Some(NetError::Ipv6EmbeddedIpv4(Box::new(
+                NetError::Ipv4Octet{ index: 3, kind: OctetError::TooLarge }
+            )))),
+        ];
+
+        let checker = ipv6();
+        for (s, ans) in check_list {
+            assert_eq!(run(&checker, s), ans, "{}", s);
+        }
+
+        let checker = ip();
+        assert_eq!(run(&checker, "10.0.0.1"), None);
+        assert_eq!(run(&checker, "::1"), None);
+        assert_eq!(run(&checker, "localhost"), Some(NetError::NotIpAddress));
+    }
+
+    /// CIDR prefixes
+    #[test]
+    fn net_rules_works02() {
+        let check_list = [
+            ("10.0.0.0/8",     None),
+            ("2001:db8::/32",  None),
+            ("10.0.0.0",       Some(NetError::CidrMissingPrefix)),
+            ("10.0.0.0/33",    Some(NetError::CidrPrefix{ max: 32 })),
+            ("10.0.0.0/x",     Some(NetError::CidrPrefix{ max: 32 })),
+            ("10.0.0.0/08",    Some(NetError::CidrPrefix{ max: 32 })),
+            ("2001:db8::/032", Some(NetError::CidrPrefix{ max: 128 })),
+            ("10.0.0.0/4",     Some(NetError::CidrPrefixRange{ prefix: 4, min: 8, max: 64 })),
+            ("2001:db8::/96",  Some(NetError::CidrPrefixRange{ prefix: 96, min: 8, max: 64 })),
+            ("10.0.0/8",       Some(NetError::Ipv4OctetCount(3))),
+        ];
+
+        let checker = cidr(8..=64);
+        for (s, ans) in check_list {
+            assert_eq!(run(&checker, s), ans, "{}", s);
+        }
+    }
+
+    /// hostnames and labels
+    #[test]
+    fn net_rules_works03() {
+        let long_label = "a".repeat(64);
+        let long_name = ["a".repeat(63), "b".repeat(63), "c".repeat(63), "d".repeat(63)].join(".");
+        let check_list = [
+            ("example.com",     None),
+            ("example.com.",    None),
+            ("localhost",       None),
+            ("3com.net",        None),
+            ("xn--bcher-kva.de", None),
+            ("-a.com",          Some(NetError::Label{ index: 0, kind: LabelError::LeadingHyphen })),
+            ("a-.com",          Some(NetError::Label{ index: 0, kind: LabelError::TrailingHyphen })),
+            ("a..com",          Some(NetError::Label{ index: 1, kind: LabelError::Empty })),
+            ("a_b.com",         Some(NetError::Label{ index: 0, kind: LabelError::InvalidChar('_') })),
+            ("1.2.3.4",         Some(NetError::Label{ index: 3, kind: LabelError::NumericTld })),
+            (long_label.as_str(), Some(NetError::Label{ index: 0, kind: LabelError::TooLong })),
+            (long_name.as_str(), Some(NetError::HostnameTooLong(255))),
+        ];
+
+        for (s, ans) in check_list {
+            let r = hostname().check(CheckState::<_, ()>::new(s));
+            assert_eq!(r.err(), ans, "{}", s);
+        }
+
+        assert!(domain_label().check(CheckState::<_, ()>::new("my-app")).is_passed());
+        assert!(domain_label().check(CheckState::<_, ()>::new("my.app")).is_failed());
+        assert!(hostname().check(CheckState::<_, ()>::new("example.com".to_string())).is_passed());
+    }
+
+    /// ports, MAC addresses, and accumulation with VecCombine
+    #[test]
+    fn net_rules_works04() {
+        let checker = port(1024..=65535);
+        assert_eq!(run(&checker, "8080"), None);
+        assert_eq!(run(&checker, "80"), Some(NetError::PortRange{ port: 80, min: 1024, max: 65535 }));
+        assert_eq!(run(&checker, "70000"), Some(NetError::PortRange{ port: 70000, min: 1024, max: 65535 }));
+        assert_eq!(run(&checker, "http"), Some(NetError::PortNotNumber));
+        assert_eq!(run(&checker, "99999999999"), Some(NetError::PortOverflow{ min: 1024, max: 65535 }));
+        assert_eq!(
+            run(&checker, "99999999999").unwrap().to_string(),
+            "port must be between 1024 and 65535"
+        );
+
+        let checker = port_range(1..=65535);
+        assert_eq!(run(&checker, "8000-8080"), None);
+        assert_eq!(run(&checker, "443"), None);
+        assert_eq!(run(&checker, "8080-8000"), Some(NetError::PortRangeFormat));
+        assert_eq!(run(&checker, "0-10"), Some(NetError::PortRange{ port: 0, min: 1, max: 65535 }));
+
+        let checker = mac();
+        assert_eq!(run(&checker, "00:1a:2b:3c:4d:5e"), None);
+        assert_eq!(run(&checker, "00-1A-2B-3C-4D-5E"), None);
+        assert_eq!(run(&checker, "001a.2b3c.4d5e"), None);
+        assert_eq!(run(&checker, "00:1a:2b:3c:4d"), Some(NetError::MacFormat));
+        assert_eq!(run(&checker, "00:1a-2b:3c:4d:5e"), Some(NetError::MacFormat));
+        assert_eq!(run(&checker, "00.1a.2b.3c.4d.5e"), Some(NetError::MacFormat));
+        assert_eq!(run(&checker, "00:1a:2b:3c:4d:zz"), Some(NetError::MacGroup{ index: 5 }));
+
+        // a host given by name or by address
+        let host = hostname().either::<_, VecCombine<NetError>>(ip());
+        assert!(host.validate(CheckState::<_, ()>::new("example.com")).is_passed());
+        assert!(host.validate(CheckState::<_, ()>::new("::1")).is_passed());
+        assert_eq!(host.validate(CheckState::<_, ()>::new("-x")).err(), Some(vec![
+            NetError::Label{ index: 0, kind: LabelError::LeadingHyphen },
+            NetError::NotIpAddress,
+        ]));
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod tests_url_email_rules {
+    use error_combinator::{
+        check::{
+            Check, CheckState, Validator,
+            rules::{
+                email::{EmailError, EmailOptions, email},
+                net::{GroupError, LabelError, NetError, OctetError},
+                url::{UrlError, UrlOptions, UrlPart, url}
+            }
+        },
+        cmberr::DefaultCombine
+    };
+
+    /// RFC 3986 URLs with the default options
+    #[test]
+    fn url_email_rules_works00() {
+        let check_list = [
+            ("https://example.com",                       None),
+            ("https://user:pw@example.com:8080/a/b?q=1#top", None),
+            ("http://192.168.0.1/",                       None),
+            ("http://[2001:db8::1]:80/",                  None),
+            ("mailto:john@example.com",                   None),
+            ("urn:isbn:0451450523",                       None),
+            ("file:///etc/hosts",                         None),
+            ("https://example.com/a%20b",                 None),
+            ("",                                          Some(UrlError::Empty)),
+            ("/a/b",                                      Some(UrlError::MissingScheme)),
+            ("example.com",                               Some(UrlError::MissingScheme)),
+            ("1http://a",                                 Some(UrlError::InvalidChar{ part: UrlPart::Scheme, offset: 0, c: '1' })),
+            ("foo://my_host/",                            None),
+            ("foo://a%20b!$/",                            None),
+            ("http://1.2.3.256",                          None),
+            ("http://a^b/",                               Some(UrlError::InvalidChar{ part: UrlPart::Host, offset: 8, c: '^' })),
+            ("http://a%2g/",                              Some(UrlError::PercentEncoding{ part: UrlPart::Host, offset: 8 })),
+            ("http://[::g]",                              Some(UrlError::Host(NetError::Ipv6Group{ index: 0, kind: GroupError::NotHex }))),
+            ("http://a.com:99999",                        Some(UrlError::Port(NetError::PortRange{ port: 99999, min: 0, max: 65535 }))),
+            ("http://a.com/a b",                          Some(UrlError::InvalidChar{ part: UrlPart::Path, offset: 14, c: ' ' })),
+            ("http://a.com/?q=%zz",                       Some(UrlError::PercentEncoding{ part: UrlPart::Query, offset: 16 })),
+            ("http://a.com/#a#b",                         Some(UrlError::InvalidChar{ part: UrlPart::Fragment, offset: 15, c: '#' })),
+            ("http://us er@a.com",                        Some(UrlError::InvalidChar{ part: UrlPart::Userinfo, offset: 9, c: ' ' })),
+            ("http://bücher.de",                          Some(UrlError::InvalidChar{ part: UrlPart::Host, offset: 8, c: 'ü' })),
+        ];
+
+        let checker = url(UrlOptions::new());
+        for (s, ans) in &check_list {
+            let r = checker.validate(CheckState::<_, ()>::new(*s));
+            assert_eq!(r.err().as_ref(), ans.as_ref(), "{}", s);
+        }
+    }
+
+    /// allowed schemes, required host, internationalised domains and HTML5 mode
+    #[test]
+    fn url_email_rules_works01() {
+        let checker = url(UrlOptions::new().schemes(["http", "https"]).require_host(true).dns_host(true));
+        let check_list = [
+            ("HTTPS://example.com",     None),
+            ("ftp://example.com",       Some(UrlError::SchemeNotAllowed("ftp".to_string()))),
+            ("http:/example.com",       Some(UrlError::MissingHost)),
+            ("http:///path",            Some(UrlError::MissingHost)),
+            ("http://exa_mple.com",     Some(UrlError::Host(NetError::Label{ index: 0, kind: LabelError::InvalidChar('_') }))),
+            ("http://1.2.3.256",        Some(UrlError::Host(NetError::Ipv4Octet{ index: 3, kind: OctetError::TooLarge }))),
+            ("http://bücher.de",        Some(UrlError::Host(NetError::Label{ index: 0, kind: LabelError::InvalidChar('ü') }))),
+        ];
+        for (s, ans) in check_list {
+            assert_eq!(checker.validate(CheckState::<_, ()>::new(s)).err(), ans, "{}", s);
+        }
+
+        // require_host and dns_host are independent
+        let checker = url(UrlOptions::new().require_host(true));
+        assert!(checker.validate(CheckState::<_, ()>::new("foo://my_host/")).is_passed());
+        assert_eq!(checker.validate(CheckState::<_, ()>::new("mailto:a@example.com")).err(), Some(UrlError::MissingHost));
+        let checker = url(UrlOptions::new().dns_host(true));
+        assert!(checker.validate(CheckState::<_, ()>::new("mailto:a@example.com")).is_passed());
+        assert_eq!(
+            checker.validate(CheckState::<_, ()>::new("foo://my_host/")).err(),
+            Some(UrlError::Host(NetError::Label{ index: 0, kind: LabelError::InvalidChar('_') }))
+        );
+
+        let too_long: String = (0..24).map(|i| char::from_u32(0x4e00 + i * 97).unwrap()).collect();
+        let too_long = format!("http://{}.com", too_long);
+        let checker = url(UrlOptions::new().idn(true));
+        let check_list = [
+            ("http://bücher.de/",            None),
+            ("http://例え.テスト/",           None),
+            ("http://xn--bcher-kva.de/",     None),
+            ("http://xn--bcher-kv.de/",      Some(UrlError::Host(NetError::Label{ index: 0, kind: LabelError::Punycode }))),
+            ("http://bü cher.de/",           Some(UrlError::Host(NetError::Label{ index: 0, kind: LabelError::InvalidChar(' ') }))),
+            (too_long.as_str(),              Some(UrlError::Host(NetError::Label{ index: 0, kind: LabelError::TooLong }))),
+        ];
+        for (s, ans) in check_list {
+            assert_eq!(checker.validate(CheckState::<_, ()>::new(s)).err(), ans, "{}", s);
+        }
+
+        let checker = url(UrlOptions::html5());
+        let check_list = [
+            ("https://example.com/ä?q=ü", None),
+            ("https://bücher.de",          None),
+            ("mailto:john@example.com",    None),
+            ("https:example.com",          Some(UrlError::MissingHost)),
+            ("https://user@example.com",   Some(UrlError::Credentials)),
+        ];
+        for (s, ans) in check_list {
+            assert_eq!(checker.validate(CheckState::<_, ()>::new(s)).err(), ans, "{}", s);
+        }
+    }
+
+    /// RFC 5322 addresses
+    #[test]
+    fn url_email_rules_works02() {
+        let long_local = format!("{}@example.com", "a".repeat(65));
+        let long_address = format!("{}@{}.com", "a".repeat(64), ["b".repeat(63), "c".repeat(63), "d".repeat(60)].join("."));
+        let check_list = [
+            ("john.doe@example.com",            None),
+            ("john+tag@sub.example.co.uk",      None),
+            ("!#$%&'*+-/=?^_`{|}~@example.com", None),
+            ("\"john doe\"@example.com",        None),
+            ("\"a\\\"b@c\"@example.com",        None),
+            ("john@[192.0.2.1]",                None),
+            ("john@[IPv6:2001:db8::1]",         None),
+            ("",                                Some(EmailError::Empty)),
+            ("john.example.com",                Some(EmailError::MissingAt)),
+            ("@example.com",                    Some(EmailError::EmptyLocalPart)),
+            (".john@example.com",               Some(EmailError::LocalPartDot{ offset: 0 })),
+            ("john..doe@example.com",           Some(EmailError::LocalPartDot{ offset: 5 })),
+            ("john.@example.com",               Some(EmailError::LocalPartDot{ offset: 4 })),
+            ("jo hn@example.com",               Some(EmailError::LocalPartChar{ offset: 2, c: ' ' })),
+            ("\"john\"x@example.com",           Some(EmailError::LocalPartChar{ offset: 6, c: 'x' })),
+            ("\"john@example.com",              Some(EmailError::UnterminatedQuote)),
+            ("jöhn@example.com",                Some(EmailError::LocalPartChar{ offset: 1, c: 'ö' })),
+            (long_local.as_str(),               Some(EmailError::LocalPartTooLong(65))),
+            (long_address.as_str(),             Some(EmailError::TooLong(257))),
+            ("john@",                           Some(EmailError::Domain(NetError::Empty))),
+            ("john@example.com.",               Some(EmailError::Domain(NetError::Label{ index: 2, kind: LabelError::Empty }))),
+            ("john@-example.com",               Some(EmailError::Domain(NetError::Label{ index: 0, kind: LabelError::LeadingHyphen }))),
+            ("john@[192.0.2.256]",              Some(EmailError::DomainLiteral(NetError::Ipv4Octet{ index: 3, kind: OctetError::TooLarge }))),
+            ("john@[192.0.2.1",                 Some(EmailError::DomainLiteral(NetError::NotIpAddress))),
+        ];
+
+        let checker = email(EmailOptions::new());
+        for (s, ans) in &check_list {
+            let r = checker.validate(CheckState::<_, ()>::new(*s));
+            assert_eq!(r.err().as_ref(), ans.as_ref(), "{}", s);
+        }
+        assert_eq!(
+            checker.validate(CheckState::<_, ()>::new("jo hn@example.com")).err().unwrap().to_string(),
+            "email local part contains ' ' at offset 2"
+        );
+    }
+
+    /// internationalised addresses and HTML5 mode
+    #[test]
+    fn url_email_rules_works03() {
+        let checker = email(EmailOptions::new().idn(true));
+        let check_list = [
+            ("jöhn@bücher.de",         None),
+            ("用户@例え.テスト",         None),
+            ("john@xn--bcher-kva.de",  None),
+            ("john@bü_cher.de",        Some(EmailError::Domain(NetError::Label{ index: 0, kind: LabelError::InvalidChar('_') }))),
+        ];
+        for (s, ans) in check_list {
+            assert_eq!(checker.validate(CheckState::<_, ()>::new(s)).err(), ans, "{}", s);
+        }
+
+        let checker = email(EmailOptions::html5());
+        let check_list = [
+            ("john.doe@example.com",   None),
+            ("john@localhost",         None),
+            ("john..doe@example.com",  None),
+            ("\"john doe\"@example.com", Some(EmailError::LocalPartChar{ offset: 0, c: '"' })),
+            ("john@[192.0.2.1]",       Some(EmailError::Domain(NetError::Label{ index: 0, kind: LabelError::InvalidChar('[') }))),
+            ("john@bücher.de",         Some(EmailError::Domain(NetError::Label{ index: 0, kind: LabelError::InvalidChar('ü') }))),
+            ("john@example..com",      Some(EmailError::Domain(NetError::Label{ index: 1, kind: LabelError::Empty }))),
+        ];
+        for (s, ans) in check_list {
+            assert_eq!(checker.validate(CheckState::<_, ()>::new(s)).err(), ans, "{}", s);
+        }
+
+        let checker = email(EmailOptions::html5().idn(true));
+        assert!(checker.validate(CheckState::<_, ()>::new("john@bücher.de")).is_passed());
+        assert!(checker.validate(CheckState::<_, ()>::new("jöhn@bücher.de")).is_failed());
+
+        // composes with other checks on an owned String
+        let checker = email::<String, ()>(EmailOptions::new())
+            .and::<_, DefaultCombine<EmailError>>(email(EmailOptions::html5()));
+        assert!(checker.check(CheckState::new("john@example.com".to_string())).is_passed());
+    }
+}